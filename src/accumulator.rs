@@ -0,0 +1,186 @@
+use core::fmt;
+use core::iter::FusedIterator;
+use core::mem::MaybeUninit;
+use core::slice;
+
+/// An owned, alloc-free buffer of up to `CAP` complete `N`-element chunks, stored inline.
+///
+/// # Examples
+///
+/// ```
+/// let mut batch = pieced::ChunkAccumulator::<u8, 4, 2>::new();
+/// assert_eq!(batch.push_chunk(*b"ping"), Ok(()));
+/// assert_eq!(batch.push_chunk(*b"pong"), Ok(()));
+/// assert_eq!(batch.push_chunk(*b"full"), Err(*b"full"));
+/// assert_eq!(batch.as_flat(), b"pingpong");
+///
+/// let sent: Vec<_> = batch.drain().collect();
+/// assert_eq!(sent, [*b"ping", *b"pong"]);
+/// assert!(batch.is_empty());
+/// ```
+pub struct ChunkAccumulator<T, const N: usize, const CAP: usize> {
+    buf: [[MaybeUninit<T>; N]; CAP],
+    /// `buf[..len]` is initialized.
+    len: usize,
+}
+
+impl<T, const N: usize, const CAP: usize> ChunkAccumulator<T, N, CAP> {
+    /// Creates an empty accumulator.
+    pub const fn new() -> Self {
+        Self {
+            buf: [const { [const { MaybeUninit::uninit() }; N] }; CAP],
+            len: 0,
+        }
+    }
+
+    /// Returns the number of chunks the accumulator can hold.
+    pub const fn capacity(&self) -> usize {
+        CAP
+    }
+
+    /// Returns the number of chunks in the accumulator.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the accumulator holds no chunks.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the accumulator can't take another chunk.
+    pub const fn is_full(&self) -> bool {
+        self.len == CAP
+    }
+
+    /// Appends a chunk, or hands it back if the accumulator is full.
+    ///
+    /// # Errors
+    ///
+    /// Returns `chunk` if the accumulator already holds `CAP` chunks.
+    pub const fn push_chunk(&mut self, chunk: [T; N]) -> Result<(), [T; N]> {
+        if self.len == CAP {
+            return Err(chunk);
+        }
+        crate::raw::write_chunk(&mut self.buf[self.len], chunk);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Returns the chunks in the accumulator.
+    pub const fn as_chunks(&self) -> &[[T; N]] {
+        let (chunks, _) = self.buf.split_at(self.len);
+        // SAFETY: `buf[..len]` is initialized.
+        unsafe { crate::raw::assume_init_chunks(chunks) }
+    }
+
+    /// Returns the chunks in the accumulator, mutably.
+    pub const fn as_chunks_mut(&mut self) -> &mut [[T; N]] {
+        let (chunks, _) = self.buf.split_at_mut(self.len);
+        // SAFETY: `buf[..len]` is initialized.
+        unsafe { crate::raw::assume_init_chunks_mut(chunks) }
+    }
+
+    /// Returns the elements of all chunks as one slice.
+    pub const fn as_flat(&self) -> &[T] {
+        self.as_chunks().as_flattened()
+    }
+
+    /// Removes every chunk, yielding them in order.
+    ///
+    /// The accumulator is empty as soon as this is called;
+    /// chunks the iterator doesn't get to yield are dropped along with it.
+    pub fn drain(&mut self) -> DrainChunks<'_, T, N> {
+        let len = core::mem::take(&mut self.len);
+        DrainChunks {
+            slots: self.buf[..len].iter_mut(),
+        }
+    }
+
+    /// Drops every chunk.
+    pub fn clear(&mut self) {
+        let chunks: *mut [[T; N]] = self.as_chunks_mut();
+        self.len = 0;
+        // SAFETY: `buf[..len]` was initialized, and is no longer considered so.
+        unsafe { core::ptr::drop_in_place(chunks) };
+    }
+}
+
+impl<T, const N: usize, const CAP: usize> Default for ChunkAccumulator<T, N, CAP> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize, const CAP: usize> Drop for ChunkAccumulator<T, N, CAP> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: fmt::Debug, const N: usize, const CAP: usize> fmt::Debug for ChunkAccumulator<T, N, CAP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChunkAccumulator")
+            .field("chunks", &self.as_chunks())
+            .finish()
+    }
+}
+
+/// Iterator that moves the chunks out of a [`ChunkAccumulator`].
+///
+/// Created by [`ChunkAccumulator::drain`].
+pub struct DrainChunks<'a, T, const N: usize> {
+    /// Every slot is initialized, and owned by the iterator.
+    slots: slice::IterMut<'a, [MaybeUninit<T>; N]>,
+}
+
+impl<T, const N: usize> DrainChunks<'_, T, N> {
+    /// Returns the chunks that haven't been yielded yet.
+    pub fn as_chunks(&self) -> &[[T; N]] {
+        // SAFETY: the remaining slots are initialized.
+        unsafe { crate::raw::assume_init_chunks(self.slots.as_slice()) }
+    }
+}
+
+impl<T, const N: usize> Iterator for DrainChunks<'_, T, N> {
+    type Item = [T; N];
+
+    fn next(&mut self) -> Option<[T; N]> {
+        // SAFETY: the slot is initialized, and the iterator won't touch it again.
+        self.slots
+            .next()
+            .map(|slot| unsafe { crate::raw::read_chunk(slot) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.slots.size_hint()
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for DrainChunks<'_, T, N> {
+    fn next_back(&mut self) -> Option<[T; N]> {
+        // SAFETY: see `next`.
+        self.slots
+            .next_back()
+            .map(|slot| unsafe { crate::raw::read_chunk(slot) })
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for DrainChunks<'_, T, N> {}
+impl<T, const N: usize> FusedIterator for DrainChunks<'_, T, N> {}
+
+impl<T, const N: usize> Drop for DrainChunks<'_, T, N> {
+    fn drop(&mut self) {
+        let slots = core::mem::take(&mut self.slots).into_slice();
+        // SAFETY: the remaining slots are initialized, and nothing reads them again.
+        unsafe { core::ptr::drop_in_place(crate::raw::assume_init_chunks_mut(slots)) };
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for DrainChunks<'_, T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DrainChunks")
+            .field(&self.as_chunks())
+            .finish()
+    }
+}
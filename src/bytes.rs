@@ -0,0 +1,67 @@
+use core::iter::Copied;
+use core::slice;
+
+/// Marks `Self` as `SIZE` bytes of plain data, which can be viewed as `[u8; SIZE]`.
+///
+/// # Safety
+///
+/// `size_of::<Self>()` must be `SIZE`, and `Self` must not have any padding (or otherwise uninitialized) bytes.
+///
+/// # Examples
+///
+/// ```
+/// #[derive(Clone, Copy)]
+/// #[repr(C)]
+/// struct Point {
+///     x: i16,
+///     y: i16,
+/// }
+/// // SAFETY: `Point` is two `i16`s, without padding.
+/// unsafe impl pieced::ToBytes<4> for Point {}
+///
+/// let bytes = pieced::as_element_bytes(&[Point { x: 1, y: -1 }])[0];
+/// assert_eq!(bytes[..2], 1i16.to_ne_bytes());
+/// assert_eq!(bytes[2..], (-1i16).to_ne_bytes());
+/// ```
+pub unsafe trait ToBytes<const SIZE: usize>: Copy {}
+
+macro_rules! to_bytes {
+    ($($t:ty),+ $(,)?) => {$(
+        // SAFETY: primitive numbers have no padding.
+        unsafe impl ToBytes<{ size_of::<$t>() }> for $t {}
+    )+};
+}
+
+to_bytes!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+/// Views every element of the slice as its bytes, in native byte order.
+///
+/// # Examples
+///
+/// ```
+/// let samples = [1u16, 0x0203];
+/// let bytes: &[[u8; 2]] = pieced::as_element_bytes(&samples);
+/// assert_eq!(bytes, [1u16.to_ne_bytes(), 0x0203u16.to_ne_bytes()]);
+/// ```
+pub const fn as_element_bytes<T: ToBytes<SIZE>, const SIZE: usize>(slice: &[T]) -> &[[u8; SIZE]] {
+    const { assert!(size_of::<T>() == SIZE) };
+    // SAFETY: `T` is `SIZE` initialized bytes (see `ToBytes`), which `[u8; SIZE]` can view with any alignment.
+    unsafe { crate::raw::cast_slice(slice) }
+}
+
+/// Iterates over the bytes of every element of the slice, in native byte order.
+///
+/// # Examples
+///
+/// ```
+/// let mut out = vec![];
+/// for bytes in pieced::element_bytes(&[1.0f32, -2.5]) {
+///     out.extend_from_slice(&bytes);
+/// }
+/// assert_eq!(out, [1.0f32.to_ne_bytes(), (-2.5f32).to_ne_bytes()].concat());
+/// ```
+pub fn element_bytes<T: ToBytes<SIZE>, const SIZE: usize>(
+    slice: &[T],
+) -> Copied<slice::Iter<'_, [u8; SIZE]>> {
+    as_element_bytes(slice).iter().copied()
+}
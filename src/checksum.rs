@@ -0,0 +1,117 @@
+use core::iter::FusedIterator;
+use core::slice;
+
+/// Iterator over a checksum of every `N`-byte chunk.
+///
+/// Created by [`checksum_pieces`].
+#[derive(Debug, Clone)]
+pub struct ChecksumPieces<'a, const N: usize, F> {
+    chunks: slice::Iter<'a, [u8; N]>,
+    rest: &'a [u8],
+    f: F,
+}
+
+/// Iterates over `f` applied to every `N`-byte chunk of `bytes`, in order.
+///
+/// Any `FnMut(&[u8; N]) -> u32` works, including [`checksum_xor`] and [`checksum_add`].
+/// The remainder isn't checksummed; see [`ChecksumPieces::remainder`].
+///
+/// # Compile-time errors
+///
+/// Fails to compile if `N` is 0.
+///
+/// # Examples
+///
+/// ```
+/// let flash = [[0x01u8; 8], [0xFF; 8]].concat();
+/// let sums: Vec<u32> = pieced::checksum_pieces::<8, _>(&flash, pieced::checksum_add).collect();
+/// assert_eq!(sums, [0x0202_0202, 0xFFFF_FFFE]);
+/// ```
+pub fn checksum_pieces<const N: usize, F: FnMut(&[u8; N]) -> u32>(
+    bytes: &[u8],
+    f: F,
+) -> ChecksumPieces<'_, N, F> {
+    let (chunks, rest) = crate::as_with_rest(bytes);
+    ChecksumPieces {
+        chunks: chunks.iter(),
+        rest,
+        f,
+    }
+}
+
+impl<'a, const N: usize, F> ChecksumPieces<'a, N, F> {
+    /// Returns the bytes after the last chunk, which never get checksummed.
+    pub const fn remainder(&self) -> &'a [u8] {
+        self.rest
+    }
+}
+
+impl<const N: usize, F: FnMut(&[u8; N]) -> u32> Iterator for ChecksumPieces<'_, N, F> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        self.chunks.next().map(&mut self.f)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
+    }
+}
+
+impl<const N: usize, F: FnMut(&[u8; N]) -> u32> DoubleEndedIterator for ChecksumPieces<'_, N, F> {
+    fn next_back(&mut self) -> Option<u32> {
+        self.chunks.next_back().map(&mut self.f)
+    }
+}
+
+impl<const N: usize, F: FnMut(&[u8; N]) -> u32> ExactSizeIterator for ChecksumPieces<'_, N, F> {}
+impl<const N: usize, F: FnMut(&[u8; N]) -> u32> FusedIterator for ChecksumPieces<'_, N, F> {}
+
+/// Reads the `i`th little-endian 32-bit word of `chunk`, zero-padding a partial last word.
+const fn word<const N: usize>(chunk: &[u8; N], i: usize) -> u32 {
+    let mut bytes = [0; 4];
+    let mut j = 0;
+    while j < 4 && i * 4 + j < N {
+        bytes[j] = chunk[i * 4 + j];
+        j += 1;
+    }
+    u32::from_le_bytes(bytes)
+}
+
+/// XORs together the little-endian 32-bit words of the chunk.
+///
+/// A partial last word is padded with zeroes.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(pieced::checksum_xor(&[1, 0, 0, 0, 3, 0, 0, 0, 0xFF]), 1 ^ 3 ^ 0xFF);
+/// ```
+pub const fn checksum_xor<const N: usize>(chunk: &[u8; N]) -> u32 {
+    let mut checksum = 0;
+    let mut i = 0;
+    while i < N.div_ceil(4) {
+        checksum ^= word(chunk, i);
+        i += 1;
+    }
+    checksum
+}
+
+/// Adds up the little-endian 32-bit words of the chunk, wrapping on overflow.
+///
+/// A partial last word is padded with zeroes.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(pieced::checksum_add(&[0xFF; 8]), 0xFFFF_FFFE);
+/// ```
+pub const fn checksum_add<const N: usize>(chunk: &[u8; N]) -> u32 {
+    let mut checksum = 0u32;
+    let mut i = 0;
+    while i < N.div_ceil(4) {
+        checksum = checksum.wrapping_add(word(chunk, i));
+        i += 1;
+    }
+    checksum
+}
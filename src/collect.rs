@@ -0,0 +1,145 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::mem::MaybeUninit;
+
+use crate::PiecedError;
+
+/// Pulls the next `N` elements out of `iter`.
+///
+/// If `iter` runs dry first, the elements pulled so far are dropped and their count is returned.
+pub(crate) fn next_chunk<T, const N: usize>(
+    iter: &mut impl Iterator<Item = T>,
+) -> Result<[T; N], usize> {
+    /// Drops the initialized part of a partially filled chunk on early return (or unwind).
+    struct Partial<T, const N: usize> {
+        buf: [MaybeUninit<T>; N],
+        /// `buf[..len]` is initialized.
+        len: usize,
+    }
+
+    impl<T, const N: usize> Drop for Partial<T, N> {
+        fn drop(&mut self) {
+            // SAFETY: `buf[..len]` is initialized.
+            unsafe {
+                core::ptr::drop_in_place(crate::raw::assume_init_mut(&mut self.buf[..self.len]))
+            };
+        }
+    }
+
+    let mut chunk = Partial::<T, N> {
+        buf: [const { MaybeUninit::uninit() }; N],
+        len: 0,
+    };
+    while chunk.len < N {
+        match iter.next() {
+            Some(element) => {
+                chunk.buf[chunk.len].write(element);
+                chunk.len += 1;
+            }
+            None => return Err(chunk.len),
+        }
+    }
+    let chunk = core::mem::ManuallyDrop::new(chunk);
+    // SAFETY: all `N` elements are initialized, and `chunk` won't drop them.
+    Ok(unsafe { crate::raw::read_chunk(&chunk.buf) })
+}
+
+/// Groups the elements of `iter` into `N`-element chunks, and pushes them onto `out`.
+///
+/// # Errors
+///
+/// Returns [`PiecedError::Remainder`] if the number of elements isn't a multiple of `N`.
+/// The complete chunks are pushed regardless; the leftover elements are dropped.
+///
+/// # Compile-time errors
+///
+/// Fails to compile if `N` is 0.
+///
+/// # Examples
+///
+/// ```
+/// let mut table = vec![[0, 0]];
+/// pieced::extend_chunked(&mut table, 1..=4).unwrap();
+/// assert_eq!(table, [[0, 0], [1, 2], [3, 4]]);
+///
+/// let err = pieced::extend_chunked(&mut table, 5..=7).unwrap_err();
+/// assert_eq!(err, pieced::PiecedError::Remainder { len: 3, rest: 1 });
+/// assert_eq!(table, [[0, 0], [1, 2], [3, 4], [5, 6]]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn extend_chunked<T, const N: usize>(
+    out: &mut Vec<[T; N]>,
+    iter: impl IntoIterator<Item = T>,
+) -> Result<(), PiecedError> {
+    crate::assert_nonzero::<N>();
+    let mut iter = iter.into_iter();
+    out.reserve(iter.size_hint().0 / N);
+    let mut len = 0;
+    loop {
+        match next_chunk(&mut iter) {
+            Ok(chunk) => {
+                out.push(chunk);
+                len += N;
+            }
+            Err(0) => return Ok(()),
+            Err(rest) => {
+                return Err(PiecedError::Remainder {
+                    len: len + rest,
+                    rest,
+                })
+            }
+        }
+    }
+}
+
+/// Collects exactly `N * M` elements from `iter` into `M` chunks of `N` elements.
+///
+/// # Errors
+///
+/// Returns [`PiecedError::LengthMismatch`] if `iter` yields fewer than `N * M` elements,
+/// and [`PiecedError::TooLong`] if it yields more.
+/// Either way, the elements pulled from `iter` are dropped.
+///
+/// # Compile-time errors
+///
+/// Fails to compile if `N` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use pieced::PiecedError;
+/// let table: [[u8; 2]; 3] = pieced::collect_exact(0..6).unwrap();
+/// assert_eq!(table, [[0, 1], [2, 3], [4, 5]]);
+///
+/// let short = pieced::collect_exact::<_, 2, 3>(0..5);
+/// assert_eq!(short, Err(PiecedError::LengthMismatch { expected: 6, actual: 5 }));
+/// let long = pieced::collect_exact::<_, 2, 3>(0..);
+/// assert_eq!(long, Err(PiecedError::TooLong { max: 6 }));
+/// ```
+pub fn collect_exact<T, const N: usize, const M: usize>(
+    iter: impl IntoIterator<Item = T>,
+) -> Result<[[T; N]; M], PiecedError> {
+    crate::assert_nonzero::<N>();
+    let mut iter = iter.into_iter();
+    let mut pulled = 0;
+    let mut chunks = core::iter::from_fn(|| match next_chunk::<T, N>(&mut iter) {
+        Ok(chunk) => {
+            pulled += N;
+            Some(chunk)
+        }
+        Err(rest) => {
+            pulled += rest;
+            None
+        }
+    });
+    let table = next_chunk::<[T; N], M>(&mut chunks);
+    let expected = N.saturating_mul(M);
+    match table {
+        Ok(_) if iter.next().is_some() => Err(PiecedError::TooLong { max: expected }),
+        Ok(table) => Ok(table),
+        Err(_) => Err(PiecedError::LengthMismatch {
+            expected,
+            actual: pulled,
+        }),
+    }
+}
@@ -0,0 +1,58 @@
+use core::fmt;
+
+/// The error type for pieced's fallible operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PiecedError {
+    /// The input doesn't split exactly into chunks.
+    Remainder {
+        /// Number of elements read from the input.
+        len: usize,
+        /// Number of elements left over after the last chunk.
+        rest: usize,
+    },
+    /// A slice didn't have the length the operation needs.
+    LengthMismatch {
+        /// Length the slice needs to have.
+        expected: usize,
+        /// Length the slice actually has.
+        actual: usize,
+    },
+    /// An iterator yielded more elements than the operation takes.
+    TooLong {
+        /// Number of elements the operation takes.
+        max: usize,
+    },
+    /// The output buffer can't hold the result.
+    BufferTooSmall {
+        /// Number of elements the operation needs to write.
+        required: usize,
+        /// Number of elements the buffer actually has.
+        available: usize,
+    },
+}
+
+impl fmt::Display for PiecedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Remainder { len, rest } => write!(
+                f,
+                "slice of {len} elements doesn't split into chunks exactly: {rest} elements left over"
+            ),
+            Self::LengthMismatch { expected, actual } => write!(
+                f,
+                "length mismatch: expected {expected} elements, but got {actual}"
+            ),
+            Self::TooLong { max } => write!(f, "too long: expected at most {max} elements"),
+            Self::BufferTooSmall {
+                required,
+                available,
+            } => write!(
+                f,
+                "buffer too small: needed {required} elements, but only {available} are available"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for PiecedError {}
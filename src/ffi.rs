@@ -0,0 +1,88 @@
+use core::ffi::CStr;
+#[cfg(feature = "std")]
+use std::ffi::OsStr;
+
+use crate::{PiecedError, Pieces};
+
+/// [`as_with_rest`](crate::as_with_rest) over the bytes of a C string, without the nul terminator.
+///
+/// # Compile-time errors
+///
+/// Fails to compile if `N` is 0.
+///
+/// # Examples
+///
+/// ```
+/// let (fields, rest) = pieced::cstr_as_with_rest::<4>(c"abcdefghij");
+/// assert_eq!(fields, [*b"abcd", *b"efgh"]);
+/// assert_eq!(rest, b"ij");
+/// ```
+pub const fn cstr_as_with_rest<const N: usize>(s: &CStr) -> (&[[u8; N]], &[u8]) {
+    crate::as_with_rest(s.to_bytes())
+}
+
+/// Fallible [`as_exact`](crate::as_exact) over the bytes of a C string, without the nul terminator.
+///
+/// # Errors
+///
+/// Returns [`PiecedError::Remainder`] if the string's length isn't a multiple of `N`.
+///
+/// # Compile-time errors
+///
+/// Fails to compile if `N` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use pieced::PiecedError;
+/// assert_eq!(pieced::cstr_as_exact::<2>(c"abcd"), Ok(&[*b"ab", *b"cd"][..]));
+/// assert_eq!(pieced::cstr_as_exact::<2>(c"abc"), Err(PiecedError::Remainder { len: 3, rest: 1 }));
+/// ```
+pub fn cstr_as_exact<const N: usize>(s: &CStr) -> Result<&[[u8; N]], PiecedError> {
+    <&Pieces<u8, N>>::try_from(s.to_bytes()).map(Pieces::as_chunks)
+}
+
+/// [`as_with_rest`](crate::as_with_rest) over the encoded bytes of an OS string.
+///
+/// The encoding is platform-specific and unspecified, see [`OsStr::as_encoded_bytes`].
+///
+/// # Compile-time errors
+///
+/// Fails to compile if `N` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use std::ffi::OsStr;
+/// let (fields, rest) = pieced::os_str_as_with_rest::<3>(OsStr::new("abcdefg"));
+/// assert_eq!(fields, [*b"abc", *b"def"]);
+/// assert_eq!(rest, b"g");
+/// ```
+#[cfg(feature = "std")]
+pub fn os_str_as_with_rest<const N: usize>(s: &OsStr) -> (&[[u8; N]], &[u8]) {
+    crate::as_with_rest(s.as_encoded_bytes())
+}
+
+/// Fallible [`as_exact`](crate::as_exact) over the encoded bytes of an OS string.
+///
+/// The encoding is platform-specific and unspecified, see [`OsStr::as_encoded_bytes`].
+///
+/// # Errors
+///
+/// Returns [`PiecedError::Remainder`] if the encoded length isn't a multiple of `N`.
+///
+/// # Compile-time errors
+///
+/// Fails to compile if `N` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use std::ffi::OsStr;
+/// assert_eq!(pieced::os_str_as_exact::<3>(OsStr::new("abcdef")), Ok(&[*b"abc", *b"def"][..]));
+/// assert!(pieced::os_str_as_exact::<4>(OsStr::new("abcdef")).is_err());
+/// ```
+#[cfg(feature = "std")]
+pub fn os_str_as_exact<const N: usize>(s: &OsStr) -> Result<&[[u8; N]], PiecedError> {
+    <&Pieces<u8, N>>::try_from(s.as_encoded_bytes()).map(Pieces::as_chunks)
+}
@@ -0,0 +1,47 @@
+/// Finds the first occurrence of `needle` in `haystack`, at any byte offset
+/// (not just multiples of `N`), and returns that offset.
+///
+/// Candidates are filtered eight offsets at a time by the needle's first and last bytes
+/// before being compared in full.
+/// An empty needle is found at offset 0.
+///
+/// # Examples
+///
+/// ```
+/// let stream = b"\x13\x37garbage\xfe\xedSYNCframe";
+/// assert_eq!(pieced::find_piece_anywhere(stream, b"\xfe\xedSYNC"), Some(9));
+/// assert_eq!(pieced::find_piece_anywhere(stream, b"SYNK"), None);
+/// ```
+pub fn find_piece_anywhere<const N: usize>(haystack: &[u8], needle: &[u8; N]) -> Option<usize> {
+    let (Some(&first), Some(&last)) = (needle.first(), needle.last()) else {
+        return Some(0);
+    };
+    let candidates = (haystack.len() + 1).checked_sub(N)?;
+    let word = |at: usize| u64::from_le_bytes(*haystack[at..].first_chunk().unwrap());
+    let mut start = 0;
+    while start + 8 <= candidates {
+        let mut hits =
+            zero_bytes(word(start) ^ splat(first)) & zero_bytes(word(start + N - 1) ^ splat(last));
+        while hits != 0 {
+            let at = start + hits.trailing_zeros() as usize / 8;
+            if haystack[at..at + N] == *needle {
+                return Some(at);
+            }
+            hits &= hits - 1;
+        }
+        start += 8;
+    }
+    (start..candidates).find(|&at| haystack[at..at + N] == *needle)
+}
+
+/// Repeats `byte` across a word.
+const fn splat(byte: u8) -> u64 {
+    u64::from_ne_bytes([byte; 8])
+}
+
+/// Sets the high bit of every zero byte of `x`.
+///
+/// Bytes above a zero byte may be flagged too, but zero bytes never go unflagged.
+const fn zero_bytes(x: u64) -> u64 {
+    x.wrapping_sub(splat(0x01)) & !x & splat(0x80)
+}
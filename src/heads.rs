@@ -0,0 +1,42 @@
+/// Splits fixed-size heads of different lengths off the front of a slice,
+/// checking the slice's length only once.
+///
+/// `split_heads!(slice; A, B, ...)` evaluates to `Some((&[T; A], &[T; B], ..., rest))`,
+/// or [`None`] if `slice` is shorter than `A + B + ...`.
+/// The sizes can be any constant expressions.
+///
+/// # Examples
+///
+/// ```
+/// let packet = b"MAGI\x00\x2a12345678payload";
+/// let (magic, len, id, payload) = pieced::split_heads!(packet; 4, 2, 8).unwrap();
+/// assert_eq!(magic, b"MAGI");
+/// assert_eq!(u16::from_be_bytes(*len), 42);
+/// assert_eq!(id, b"12345678");
+/// assert_eq!(payload, b"payload");
+///
+/// assert!(pieced::split_heads!(&packet[..13]; 4, 2, { 4 * 2 }).is_none());
+/// ```
+#[macro_export]
+macro_rules! split_heads {
+    ($slice:expr; $($n:expr),+ $(,)?) => {{
+        let mut rest: &[_] = $slice;
+        if rest.len() < 0 $(+ $n)+ {
+            ::core::option::Option::None
+        } else {
+            ::core::option::Option::Some(($($crate::__split_head::<_, { $n }>(&mut rest),)+ rest))
+        }
+    }};
+}
+
+/// Splits an `N`-element head off `rest`, which [`split_heads!`] has checked is long enough.
+#[doc(hidden)]
+pub const fn __split_head<'a, T, const N: usize>(rest: &mut &'a [T]) -> &'a [T; N] {
+    match rest.split_first_chunk() {
+        Some((head, tail)) => {
+            *rest = tail;
+            head
+        }
+        None => unreachable!(),
+    }
+}
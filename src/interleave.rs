@@ -0,0 +1,95 @@
+use crate::PiecedError;
+
+/// Interleaves `N` equally long slices into `dst`, so that
+/// `dst` holds `[srcs[0][0], srcs[1][0], ..., srcs[0][1], srcs[1][1], ...]`.
+///
+/// # Errors
+///
+/// Returns [`PiecedError::LengthMismatch`] (and leaves `dst` untouched)
+/// if the sources differ in length, or `dst` doesn't hold exactly `N` times that many elements.
+///
+/// # Compile-time errors
+///
+/// Fails to compile if `N` is 0.
+///
+/// # Examples
+///
+/// ```
+/// let (left, right) = ([1, 2, 3], [-1, -2, -3]);
+/// let mut frames = [0; 6];
+/// pieced::interleave_into([&left, &right], &mut frames).unwrap();
+/// assert_eq!(frames, [1, -1, 2, -2, 3, -3]);
+///
+/// let err = pieced::interleave_into([&left, &right], &mut frames[..5]).unwrap_err();
+/// assert_eq!(err, pieced::PiecedError::LengthMismatch { expected: 6, actual: 5 });
+/// ```
+pub fn interleave_into<T: Copy, const N: usize>(
+    srcs: [&[T]; N],
+    dst: &mut [T],
+) -> Result<(), PiecedError> {
+    let len = plane_len(srcs.iter().map(|src| src.len()), dst.len())?;
+    let srcs = srcs.map(|src| &src[..len]);
+    let (frames, _) = crate::as_with_rest_mut::<T, N>(dst);
+    for (i, frame) in frames.iter_mut().enumerate() {
+        for (slot, src) in frame.iter_mut().zip(srcs) {
+            *slot = src[i];
+        }
+    }
+    Ok(())
+}
+
+/// Checks that every plane is as long as the first,
+/// and that `total` covers all of them, returning the common plane length.
+fn plane_len(
+    mut planes: impl ExactSizeIterator<Item = usize>,
+    total: usize,
+) -> Result<usize, PiecedError> {
+    let n = planes.len();
+    let len = planes.next().unwrap_or(0);
+    if let Some(actual) = planes.find(|&plane| plane != len) {
+        return Err(PiecedError::LengthMismatch {
+            expected: len,
+            actual,
+        });
+    }
+    match len.checked_mul(n) {
+        Some(expected) if expected == total => Ok(len),
+        expected => Err(PiecedError::LengthMismatch {
+            expected: expected.unwrap_or(usize::MAX),
+            actual: total,
+        }),
+    }
+}
+
+/// Scatters the chunks into `N` planes, so that `dsts[j][i] == src[i][j]`.
+///
+/// # Errors
+///
+/// Returns [`PiecedError::LengthMismatch`] (and leaves `dsts` untouched)
+/// if any destination doesn't hold exactly `src.len()` elements.
+///
+/// # Examples
+///
+/// ```
+/// let pixels = [[255, 0, 0], [0, 255, 0]];
+/// let (mut r, mut g, mut b) = ([0; 2], [0; 2], [0; 2]);
+/// pieced::unzip_chunks(&pixels, [&mut r, &mut g, &mut b]).unwrap();
+/// assert_eq!((r, g, b), ([255, 0], [0, 255], [0, 0]));
+/// ```
+pub fn unzip_chunks<T: Copy, const N: usize>(
+    src: &[[T; N]],
+    dsts: [&mut [T]; N],
+) -> Result<(), PiecedError> {
+    if let Some(dst) = dsts.iter().find(|dst| dst.len() != src.len()) {
+        return Err(PiecedError::LengthMismatch {
+            expected: src.len(),
+            actual: dst.len(),
+        });
+    }
+    for (j, dst) in dsts.into_iter().enumerate() {
+        for (slot, chunk) in dst.iter_mut().zip(src) {
+            *slot = chunk[j];
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,591 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::cell::Cell;
+use core::fmt;
+use core::iter::{Enumerate, FusedIterator, StepBy};
+use core::ops::Range;
+use core::slice;
+
+/// Iterator over the `N`-element chunks of a slice, paired with the element offset each chunk starts at.
+///
+/// Created by [`enumerate_pieces`].
+#[derive(Debug)]
+pub struct EnumeratePieces<'a, T, const N: usize> {
+    chunks: Enumerate<slice::Iter<'a, [T; N]>>,
+    rest: &'a [T],
+}
+
+impl<T, const N: usize> Clone for EnumeratePieces<'_, T, N> {
+    fn clone(&self) -> Self {
+        Self {
+            chunks: self.chunks.clone(),
+            rest: self.rest,
+        }
+    }
+}
+
+/// Iterates over the `N`-element chunks of the slice, yielding `(offset, chunk)`
+/// where `offset` is the index of the chunk's first element in `slice`.
+///
+/// The offsets travel with the chunks, so they stay correct through `filter`, `rev`, `skip` and friends.
+///
+/// # Compile-time errors
+///
+/// Fails to compile if `N` is 0.
+///
+/// # Examples
+///
+/// ```
+/// let bytes = b"okokNOok!";
+/// let mut chunks = pieced::enumerate_pieces::<_, 2>(bytes);
+/// let bad = chunks.by_ref().filter(|(_, chunk)| *chunk != b"ok").map(|(at, _)| at);
+/// assert_eq!(bad.collect::<Vec<_>>(), [4]);
+/// assert_eq!(chunks.remainder(), b"!");
+/// ```
+pub fn enumerate_pieces<T, const N: usize>(slice: &[T]) -> EnumeratePieces<'_, T, N> {
+    let (chunks, rest) = crate::as_with_rest(slice);
+    EnumeratePieces {
+        chunks: chunks.iter().enumerate(),
+        rest,
+    }
+}
+
+impl<'a, T, const N: usize> EnumeratePieces<'a, T, N> {
+    /// Returns the elements after the last chunk, which the iterator never yields.
+    pub const fn remainder(&self) -> &'a [T] {
+        self.rest
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for EnumeratePieces<'a, T, N> {
+    type Item = (usize, &'a [T; N]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.chunks.next().map(|(i, chunk)| (i * N, chunk))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.chunks.nth(n).map(|(i, chunk)| (i * N, chunk))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for EnumeratePieces<'_, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.chunks.next_back().map(|(i, chunk)| (i * N, chunk))
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for EnumeratePieces<'_, T, N> {}
+impl<T, const N: usize> FusedIterator for EnumeratePieces<'_, T, N> {}
+
+/// Folds every `N`-element chunk of the slice into an accumulator with `f`,
+/// then finishes with `rest`, which gets the remainder (possibly empty).
+///
+/// # Compile-time errors
+///
+/// Fails to compile if `N` is 0.
+///
+/// # Examples
+///
+/// ```
+/// let sum = pieced::fold_chunks::<_, 4, _>(
+///     &[1u32, 2, 3, 4, 5, 6, 7, 8, 9, 10],
+///     0,
+///     |acc, chunk| acc + chunk.iter().sum::<u32>(),
+///     |acc, rest| acc + rest.iter().sum::<u32>(),
+/// );
+/// assert_eq!(sum, 55);
+/// ```
+#[inline]
+pub fn fold_chunks<'a, T, const N: usize, B>(
+    slice: &'a [T],
+    init: B,
+    f: impl FnMut(B, &'a [T; N]) -> B,
+    rest: impl FnOnce(B, &'a [T]) -> B,
+) -> B {
+    let (chunks, remainder) = crate::as_with_rest(slice);
+    rest(chunks.iter().fold(init, f), remainder)
+}
+
+/// Calls `f` with every `N`-element chunk of the slice,
+/// then `rest` with the remainder (possibly empty).
+///
+/// # Compile-time errors
+///
+/// Fails to compile if `N` is 0.
+///
+/// # Examples
+///
+/// ```
+/// let (mut pairs, mut tail) = (vec![], None);
+/// pieced::for_each_chunk(b"abcde", |pair: &[u8; 2]| pairs.push(*pair), |rest| tail = Some(rest));
+/// assert_eq!(pairs, [*b"ab", *b"cd"]);
+/// assert_eq!(tail, Some(&b"e"[..]));
+/// ```
+#[inline]
+pub fn for_each_chunk<'a, T, const N: usize>(
+    slice: &'a [T],
+    f: impl FnMut(&'a [T; N]),
+    rest: impl FnOnce(&'a [T]),
+) {
+    let (chunks, remainder) = crate::as_with_rest(slice);
+    chunks.iter().for_each(f);
+    rest(remainder);
+}
+
+/// Iterator over runs of consecutive equal chunks.
+///
+/// Created by [`runs`].
+#[derive(Debug)]
+pub struct Runs<'a, T, const N: usize> {
+    chunks: &'a [[T; N]],
+}
+
+impl<T, const N: usize> Clone for Runs<'_, T, N> {
+    fn clone(&self) -> Self {
+        Self {
+            chunks: self.chunks,
+        }
+    }
+}
+
+/// Iterates over runs of consecutive equal chunks, yielding each run's chunk and its length.
+///
+/// # Examples
+///
+/// ```
+/// let tiles = [[0, 1], [0, 1], [0, 1], [2, 2], [0, 1]];
+/// let runs: Vec<_> = pieced::runs(&tiles).collect();
+/// assert_eq!(runs, [(&[0, 1], 3), (&[2, 2], 1), (&[0, 1], 1)]);
+/// ```
+pub const fn runs<T: PartialEq, const N: usize>(chunks: &[[T; N]]) -> Runs<'_, T, N> {
+    Runs { chunks }
+}
+
+impl<'a, T: PartialEq, const N: usize> Iterator for Runs<'a, T, N> {
+    type Item = (&'a [T; N], usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (first, rest) = self.chunks.split_first()?;
+        let len = 1 + rest.iter().take_while(|chunk| *chunk == first).count();
+        self.chunks = &self.chunks[len..];
+        Some((first, len))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (
+            usize::from(!self.chunks.is_empty()),
+            Some(self.chunks.len()),
+        )
+    }
+}
+
+impl<T: PartialEq, const N: usize> FusedIterator for Runs<'_, T, N> {}
+
+/// Iterator over runs of consecutive chunks with equal keys.
+///
+/// Created by [`runs_by_key`].
+#[derive(Debug)]
+pub struct RunsByKey<'a, T, const N: usize, F> {
+    chunks: &'a [[T; N]],
+    key: F,
+}
+
+impl<T, const N: usize, F: Clone> Clone for RunsByKey<'_, T, N, F> {
+    fn clone(&self) -> Self {
+        Self {
+            chunks: self.chunks,
+            key: self.key.clone(),
+        }
+    }
+}
+
+/// Iterates over runs of consecutive chunks that `key` maps to equal keys,
+/// yielding each run's first chunk and its length.
+///
+/// # Examples
+///
+/// ```
+/// let samples = [[1, 10], [1, 20], [2, 30], [2, 30], [1, 40]];
+/// let runs = pieced::runs_by_key(&samples, |[channel, _]| *channel);
+/// let lens: Vec<_> = runs.map(|([channel, _], len)| (*channel, len)).collect();
+/// assert_eq!(lens, [(1, 2), (2, 2), (1, 1)]);
+/// ```
+pub fn runs_by_key<T, const N: usize, K: PartialEq, F: FnMut(&[T; N]) -> K>(
+    chunks: &[[T; N]],
+    key: F,
+) -> RunsByKey<'_, T, N, F> {
+    RunsByKey { chunks, key }
+}
+
+impl<'a, T, const N: usize, K: PartialEq, F: FnMut(&[T; N]) -> K> Iterator
+    for RunsByKey<'a, T, N, F>
+{
+    type Item = (&'a [T; N], usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (first, rest) = self.chunks.split_first()?;
+        let run = (self.key)(first);
+        let len = 1 + rest
+            .iter()
+            .take_while(|chunk| (self.key)(chunk) == run)
+            .count();
+        self.chunks = &self.chunks[len..];
+        Some((first, len))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (
+            usize::from(!self.chunks.is_empty()),
+            Some(self.chunks.len()),
+        )
+    }
+}
+
+impl<T, const N: usize, K: PartialEq, F: FnMut(&[T; N]) -> K> FusedIterator
+    for RunsByKey<'_, T, N, F>
+{
+}
+
+/// Iterator over overlapping `N`-element windows of cells.
+///
+/// Created by [`windows_of_cells`].
+pub struct CellWindows<'a, T, const N: usize> {
+    windows: slice::Windows<'a, Cell<T>>,
+}
+
+impl<T, const N: usize> Clone for CellWindows<'_, T, N> {
+    fn clone(&self) -> Self {
+        Self {
+            windows: self.windows.clone(),
+        }
+    }
+}
+
+impl<T, const N: usize> fmt::Debug for CellWindows<'_, T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CellWindows")
+            .field("remaining", &self.windows.len())
+            .finish()
+    }
+}
+
+/// Iterates over all overlapping `N`-element windows of the slice, viewed as [`Cell`]s.
+///
+/// Windows share elements, and every window (and every item kept from previous ones)
+/// can be read and written at the same time.
+///
+/// # Compile-time errors
+///
+/// Fails to compile if `N` is 0.
+///
+/// # Examples
+///
+/// ```
+/// let mut values = [1, 1, 0, 0, 0, 0];
+/// for [a, b, c] in pieced::windows_of_cells(&mut values) {
+///     c.set(a.get() + b.get());
+/// }
+/// assert_eq!(values, [1, 1, 2, 3, 5, 8]);
+/// ```
+pub fn windows_of_cells<T, const N: usize>(slice: &mut [T]) -> CellWindows<'_, T, N> {
+    crate::assert_nonzero::<N>();
+    CellWindows {
+        windows: Cell::from_mut(slice).as_slice_of_cells().windows(N),
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for CellWindows<'a, T, N> {
+    type Item = &'a [Cell<T>; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.windows.next().and_then(<[_]>::first_chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.windows.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.windows.nth(n).and_then(<[_]>::first_chunk)
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for CellWindows<'_, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.windows.next_back().and_then(<[_]>::first_chunk)
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for CellWindows<'_, T, N> {}
+impl<T, const N: usize> FusedIterator for CellWindows<'_, T, N> {}
+
+/// Iterates over the element offset of every `N`-element chunk [`as_with_rest`](crate::as_with_rest) splits the slice into.
+///
+/// # Compile-time errors
+///
+/// Fails to compile if `N` is 0.
+///
+/// # Examples
+///
+/// ```
+/// let offsets: Vec<_> = pieced::offsets::<_, 4>(&[0u8; 10]).collect();
+/// assert_eq!(offsets, [0, 4]);
+/// ```
+pub fn offsets<T, const N: usize>(slice: &[T]) -> StepBy<Range<usize>> {
+    let (chunks, _) = crate::as_with_rest::<T, N>(slice);
+    (0..chunks.len() * N).step_by(N)
+}
+
+/// Collects the element offset of every `N`-element chunk of the slice,
+/// followed by the offset of the remainder.
+///
+/// The last entry is the offset where the remainder starts (the end of the chunks),
+/// even if the remainder is empty, so consecutive entries delimit each chunk.
+///
+/// # Compile-time errors
+///
+/// Fails to compile if `N` is 0.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(pieced::offset_table::<_, 4>(&[0u8; 10]), [0, 4, 8]);
+/// assert_eq!(pieced::offset_table::<_, 4>(&[0u8; 8]), [0, 4, 8]);
+/// assert_eq!(pieced::offset_table::<_, 4>(&[0u8; 3]), [0]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn offset_table<T, const N: usize>(slice: &[T]) -> Vec<usize> {
+    let (chunks, _) = crate::as_with_rest::<T, N>(slice);
+    let mut table = Vec::with_capacity(chunks.len() + 1);
+    table.extend(offsets::<T, N>(slice));
+    table.push(chunks.len() * N);
+    table
+}
+
+/// Iterator over `OUTER`-element regions, each split into `INNER`-element chunks and a remainder.
+///
+/// Created by [`pieces_of_pieces`].
+#[derive(Debug)]
+pub struct PiecesOfPieces<'a, T, const OUTER: usize, const INNER: usize> {
+    regions: slice::Iter<'a, [T; OUTER]>,
+    rest: &'a [T],
+}
+
+impl<T, const OUTER: usize, const INNER: usize> Clone for PiecesOfPieces<'_, T, OUTER, INNER> {
+    fn clone(&self) -> Self {
+        Self {
+            regions: self.regions.clone(),
+            rest: self.rest,
+        }
+    }
+}
+
+/// Iterates over the `OUTER`-element regions of the slice, yielding every region
+/// [split](crate::as_with_rest) into `INNER`-element chunks and a remainder shorter than `INNER`.
+///
+/// Each region has the same remainder length, `OUTER % INNER`.
+/// The elements after the last region are available through [`PiecesOfPieces::remainder`].
+///
+/// # Compile-time errors
+///
+/// Fails to compile if `OUTER` or `INNER` is 0.
+///
+/// # Examples
+///
+/// ```
+/// // 10-byte sectors holding three 3-byte records and a 1-byte checksum.
+/// let disk = b"abcdefghi!jklmnopqr?st";
+/// let mut sectors = pieced::pieces_of_pieces::<_, 10, 3>(disk);
+/// let (records, checksum) = sectors.next().unwrap();
+/// assert_eq!(records, [*b"abc", *b"def", *b"ghi"]);
+/// assert_eq!(checksum, b"!");
+/// assert_eq!(sectors.next().unwrap().1, b"?");
+/// assert_eq!(sectors.next(), None);
+/// assert_eq!(sectors.remainder(), b"st");
+/// ```
+pub fn pieces_of_pieces<T, const OUTER: usize, const INNER: usize>(
+    slice: &[T],
+) -> PiecesOfPieces<'_, T, OUTER, INNER> {
+    crate::assert_nonzero::<INNER>();
+    let (regions, rest) = crate::as_with_rest(slice);
+    PiecesOfPieces {
+        regions: regions.iter(),
+        rest,
+    }
+}
+
+impl<'a, T, const OUTER: usize, const INNER: usize> PiecesOfPieces<'a, T, OUTER, INNER> {
+    /// Returns the elements after the last region, which the iterator never yields.
+    pub const fn remainder(&self) -> &'a [T] {
+        self.rest
+    }
+}
+
+impl<'a, T, const OUTER: usize, const INNER: usize> Iterator
+    for PiecesOfPieces<'a, T, OUTER, INNER>
+{
+    type Item = (&'a [[T; INNER]], &'a [T]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.regions
+            .next()
+            .map(|region| crate::as_with_rest(region))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.regions.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.regions
+            .nth(n)
+            .map(|region| crate::as_with_rest(region))
+    }
+}
+
+impl<T, const OUTER: usize, const INNER: usize> DoubleEndedIterator
+    for PiecesOfPieces<'_, T, OUTER, INNER>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.regions
+            .next_back()
+            .map(|region| crate::as_with_rest(region))
+    }
+}
+
+impl<T, const OUTER: usize, const INNER: usize> ExactSizeIterator
+    for PiecesOfPieces<'_, T, OUTER, INNER>
+{
+}
+impl<T, const OUTER: usize, const INNER: usize> FusedIterator
+    for PiecesOfPieces<'_, T, OUTER, INNER>
+{
+}
+
+/// Iterator over the folds of every window of `W` consecutive chunks.
+///
+/// Created by [`windowed_fold_chunks`].
+pub struct WindowedFoldChunks<'a, T, const N: usize, const W: usize, B, F> {
+    windows: slice::Windows<'a, [T; N]>,
+    init: B,
+    f: F,
+}
+
+/// Iterates over every window of `W` consecutive chunks, yielding each window's chunks
+/// folded, in order, into a fresh copy of `init` with `f`.
+///
+/// Each window is folded from scratch, in `O(W)` time.
+/// Yields nothing if there are fewer than `W` chunks.
+///
+/// # Compile-time errors
+///
+/// Fails to compile if `W` is 0.
+///
+/// # Examples
+///
+/// ```
+/// let frames = [[1u8, 9], [4, 2], [0, 7], [5, 5]];
+/// let peaks: Vec<_> = pieced::windowed_fold_chunks::<_, 2, 3, _, _>(&frames, 0, |peak, frame| {
+///     frame.iter().fold(peak, |peak, &x| peak.max(x))
+/// })
+/// .collect();
+/// assert_eq!(peaks, [9, 7]);
+/// ```
+pub fn windowed_fold_chunks<T, const N: usize, const W: usize, B: Clone, F>(
+    chunks: &[[T; N]],
+    init: B,
+    f: F,
+) -> WindowedFoldChunks<'_, T, N, W, B, F>
+where
+    F: FnMut(B, &[T; N]) -> B,
+{
+    crate::assert_nonzero::<W>();
+    WindowedFoldChunks {
+        windows: chunks.windows(W),
+        init,
+        f,
+    }
+}
+
+impl<T, const N: usize, const W: usize, B: Clone, F: Clone> Clone
+    for WindowedFoldChunks<'_, T, N, W, B, F>
+{
+    fn clone(&self) -> Self {
+        Self {
+            windows: self.windows.clone(),
+            init: self.init.clone(),
+            f: self.f.clone(),
+        }
+    }
+}
+
+impl<T, const N: usize, const W: usize, B: Clone, F> WindowedFoldChunks<'_, T, N, W, B, F>
+where
+    F: FnMut(B, &[T; N]) -> B,
+{
+    fn fold(&mut self, window: &[[T; N]]) -> B {
+        window.iter().fold(self.init.clone(), &mut self.f)
+    }
+}
+
+impl<T, const N: usize, const W: usize, B: Clone, F> Iterator
+    for WindowedFoldChunks<'_, T, N, W, B, F>
+where
+    F: FnMut(B, &[T; N]) -> B,
+{
+    type Item = B;
+
+    fn next(&mut self) -> Option<B> {
+        let window = self.windows.next()?;
+        Some(self.fold(window))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.windows.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<B> {
+        let window = self.windows.nth(n)?;
+        Some(self.fold(window))
+    }
+}
+
+impl<T, const N: usize, const W: usize, B: Clone, F> DoubleEndedIterator
+    for WindowedFoldChunks<'_, T, N, W, B, F>
+where
+    F: FnMut(B, &[T; N]) -> B,
+{
+    fn next_back(&mut self) -> Option<B> {
+        let window = self.windows.next_back()?;
+        Some(self.fold(window))
+    }
+}
+
+impl<T, const N: usize, const W: usize, B: Clone, F> ExactSizeIterator
+    for WindowedFoldChunks<'_, T, N, W, B, F>
+where
+    F: FnMut(B, &[T; N]) -> B,
+{
+}
+impl<T, const N: usize, const W: usize, B: Clone, F> FusedIterator
+    for WindowedFoldChunks<'_, T, N, W, B, F>
+where
+    F: FnMut(B, &[T; N]) -> B,
+{
+}
+
+impl<T: fmt::Debug, const N: usize, const W: usize, B: fmt::Debug, F> fmt::Debug
+    for WindowedFoldChunks<'_, T, N, W, B, F>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WindowedFoldChunks")
+            .field("windows", &self.windows)
+            .field("init", &self.init)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,100 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::PiecedError;
+
+/// Returns the number of elements [`join_pieces`] (or [`join_into`]) produces
+/// for these `chunks` and separator.
+///
+/// # Panics
+///
+/// Panics if the joined length overflows `usize`.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(pieced::joined_len(&[[1, 2], [3, 4], [5, 6]], &[0]), 8);
+/// assert_eq!(pieced::joined_len::<u8, 2>(&[], &[0]), 0);
+/// ```
+pub const fn joined_len<T, const N: usize>(chunks: &[[T; N]], sep: &[T]) -> usize {
+    checked_joined_len(chunks, sep).expect("joined length overflows usize")
+}
+
+/// [`joined_len`], or [`None`] if it overflows `usize`.
+const fn checked_joined_len<T, const N: usize>(chunks: &[[T; N]], sep: &[T]) -> Option<usize> {
+    match chunks.len() {
+        0 => Some(0),
+        n => match (crate::flat_len::<N>(n), (n - 1).checked_mul(sep.len())) {
+            (Some(chunks), Some(seps)) => chunks.checked_add(seps),
+            _ => None,
+        },
+    }
+}
+
+/// Flattens the chunks into a vector, placing `sep` between each pair of chunks.
+///
+/// # Examples
+///
+/// ```
+/// let fields = [*b"abc", *b"def", *b"ghi"];
+/// assert_eq!(pieced::join_pieces(&fields, b", "), b"abc, def, ghi");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn join_pieces<T: Copy, const N: usize>(chunks: &[[T; N]], sep: &[T]) -> Vec<T> {
+    let mut out = Vec::with_capacity(joined_len(chunks, sep));
+    if let [first, rest @ ..] = chunks {
+        out.extend_from_slice(first);
+        for chunk in rest {
+            out.extend_from_slice(sep);
+            out.extend_from_slice(chunk);
+        }
+    }
+    out
+}
+
+/// Flattens the chunks into `out`, placing `sep` between each pair of chunks,
+/// and returns the number of elements written.
+///
+/// Use [`joined_len`] to size the buffer up front.
+///
+/// # Errors
+///
+/// Returns [`PiecedError::BufferTooSmall`] (and leaves `out` untouched)
+/// if `out` is shorter than [`joined_len`]`(chunks, sep)`,
+/// with `required: usize::MAX` if that length overflows `usize`.
+///
+/// # Examples
+///
+/// ```
+/// let mut out = [0; 16];
+/// let n = pieced::join_into(&[*b"ab", *b"cd"], b"|", &mut out).unwrap();
+/// assert_eq!(&out[..n], b"ab|cd");
+///
+/// let err = pieced::join_into(&[*b"ab", *b"cd"], b"|", &mut out[..4]).unwrap_err();
+/// assert_eq!(err, pieced::PiecedError::BufferTooSmall { required: 5, available: 4 });
+/// ```
+pub fn join_into<T: Copy, const N: usize>(
+    chunks: &[[T; N]],
+    sep: &[T],
+    out: &mut [T],
+) -> Result<usize, PiecedError> {
+    let required = match checked_joined_len(chunks, sep) {
+        Some(required) if required <= out.len() => required,
+        required => {
+            return Err(PiecedError::BufferTooSmall {
+                required: required.unwrap_or(usize::MAX),
+                available: out.len(),
+            })
+        }
+    };
+    let mut at = 0;
+    for (i, chunk) in chunks.iter().enumerate() {
+        if i != 0 {
+            out[at..at + sep.len()].copy_from_slice(sep);
+            at += sep.len();
+        }
+        out[at..at + N].copy_from_slice(chunk);
+        at += N;
+    }
+    Ok(required)
+}
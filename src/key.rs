@@ -0,0 +1,84 @@
+use core::borrow::Borrow;
+
+/// A fixed-size byte record that can be used as a map key.
+///
+/// Comparison and hashing agree with `[u8]`,
+/// so maps keyed by [`ChunkKey`] can be queried with plain byte slices.
+///
+/// # Examples
+///
+/// ```
+/// # use std::collections::BTreeMap;
+/// use pieced::ChunkKey;
+/// let records: &[[u8; 4]] = pieced::as_exact(b"key1key2");
+/// let map: BTreeMap<_, _> = records.iter().map(ChunkKey::from).zip(0..).collect();
+/// assert_eq!(map.get(&b"key2"[..]), Some(&1));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct ChunkKey<const N: usize>(pub [u8; N]);
+
+impl<const N: usize> ChunkKey<N> {
+    /// Views a chunk as a key, without copying.
+    pub const fn from_ref(chunk: &[u8; N]) -> &Self {
+        &Self::from_slice(core::slice::from_ref(chunk))[0]
+    }
+
+    /// Views a slice of chunks as a slice of keys, without copying.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pieced::ChunkKey;
+    /// let keys = ChunkKey::from_slice(pieced::as_exact::<_, 2>(b"bbaacc"));
+    /// assert_eq!(keys.iter().min(), Some(&ChunkKey(*b"aa")));
+    /// ```
+    pub const fn from_slice(chunks: &[[u8; N]]) -> &[Self] {
+        crate::wrap_chunks(chunks)
+    }
+}
+
+// SAFETY: `ChunkKey<N>` is a `repr(transparent)` wrapper around `[u8; N]`.
+unsafe impl<const N: usize> crate::FromChunk<u8, N> for ChunkKey<N> {}
+
+impl<const N: usize> From<[u8; N]> for ChunkKey<N> {
+    fn from(chunk: [u8; N]) -> Self {
+        Self(chunk)
+    }
+}
+
+impl<const N: usize> From<&[u8; N]> for ChunkKey<N> {
+    fn from(chunk: &[u8; N]) -> Self {
+        Self(*chunk)
+    }
+}
+
+impl<const N: usize> From<ChunkKey<N>> for [u8; N] {
+    fn from(key: ChunkKey<N>) -> Self {
+        key.0
+    }
+}
+
+impl<'a, const N: usize> From<&'a [u8; N]> for &'a ChunkKey<N> {
+    fn from(chunk: &'a [u8; N]) -> Self {
+        ChunkKey::from_ref(chunk)
+    }
+}
+
+impl<const N: usize> Borrow<[u8]> for ChunkKey<N> {
+    fn borrow(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<const N: usize> Borrow<[u8; N]> for ChunkKey<N> {
+    fn borrow(&self) -> &[u8; N] {
+        &self.0
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for ChunkKey<N> {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
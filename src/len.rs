@@ -0,0 +1,47 @@
+/// Returns how many `N`-element chunks it takes to hold `len` elements, counting a partial last chunk.
+///
+/// # Compile-time errors
+///
+/// Fails to compile if `N` is 0.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(pieced::chunks_needed::<4>(9), 3);
+/// assert_eq!(pieced::chunks_needed::<4>(8), 2);
+/// assert_eq!(pieced::chunks_needed::<4>(usize::MAX), usize::MAX / 4 + 1);
+/// ```
+pub const fn chunks_needed<const N: usize>(len: usize) -> usize {
+    crate::assert_nonzero::<N>();
+    len.div_ceil(N)
+}
+
+/// Returns the number of elements in `chunks` `N`-element chunks,
+/// or [`None`] if that overflows `usize`.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(pieced::flat_len::<4>(3), Some(12));
+/// assert_eq!(pieced::flat_len::<4>(usize::MAX / 2), None);
+/// ```
+pub const fn flat_len<const N: usize>(chunks: usize) -> Option<usize> {
+    chunks.checked_mul(N)
+}
+
+/// Returns how many complete `N`-element chunks fit in `capacity` elements.
+///
+/// # Compile-time errors
+///
+/// Fails to compile if `N` is 0.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(pieced::max_chunks_in::<4>(9), 2);
+/// assert_eq!(pieced::max_chunks_in::<4>(3), 0);
+/// ```
+pub const fn max_chunks_in<const N: usize>(capacity: usize) -> usize {
+    crate::assert_nonzero::<N>();
+    capacity / N
+}
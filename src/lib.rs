@@ -1,66 +1,470 @@
 #![no_std]
-/// Splits the slice into a slice of `N`-element arrays,
-/// starting at the beginning of the slice,
-/// and a rest slice with length strictly less than `N`.
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod accumulator;
+mod bytes;
+mod checksum;
+mod cmp;
+mod collect;
+mod each;
+mod error;
+mod ffi;
+mod find;
+mod heads;
+mod interleave;
+mod iter;
+mod join;
+mod key;
+mod len;
+#[cfg(feature = "alloc")]
+mod owned;
+mod pieces;
+mod raw;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+mod shared;
+mod sort;
+mod split;
+mod stream;
+mod strided;
+mod wrap;
+
+pub use accumulator::{ChunkAccumulator, DrainChunks};
+pub use bytes::{as_element_bytes, element_bytes, ToBytes};
+pub use checksum::{checksum_add, checksum_pieces, checksum_xor, ChecksumPieces};
+pub use cmp::{
+    cmp_pieces, diff_chunks, eq_chunk_u8, eq_pieces, mismatch_bytes, mismatch_pieces,
+    starts_with_u8, DiffChunks,
+};
+pub use collect::collect_exact;
+#[cfg(feature = "alloc")]
+pub use collect::extend_chunked;
+pub use each::{
+    reverse_each_chunk, reverse_each_chunk_flat, rotate_each_chunk_left,
+    rotate_each_chunk_left_copied, rotate_each_chunk_right, rotate_each_chunk_right_copied,
+};
+pub use error::PiecedError;
+pub use ffi::{cstr_as_exact, cstr_as_with_rest};
+#[cfg(feature = "std")]
+pub use ffi::{os_str_as_exact, os_str_as_with_rest};
+pub use find::find_piece_anywhere;
+#[doc(hidden)]
+pub use heads::__split_head;
+pub use interleave::{interleave_into, unzip_chunks};
+#[cfg(feature = "alloc")]
+pub use iter::offset_table;
+pub use iter::{
+    enumerate_pieces, fold_chunks, for_each_chunk, offsets, pieces_of_pieces, runs, runs_by_key,
+    windowed_fold_chunks, windows_of_cells, CellWindows, EnumeratePieces, PiecesOfPieces, Runs,
+    RunsByKey, WindowedFoldChunks,
+};
+#[cfg(feature = "alloc")]
+pub use join::join_pieces;
+pub use join::{join_into, joined_len};
+pub use key::ChunkKey;
+pub use len::{chunks_needed, flat_len, max_chunks_in};
+#[cfg(feature = "alloc")]
+pub use owned::{
+    flatten_box, flatten_vec, into_exact_box, into_exact_vec, into_pieces, IntoPieces, PiecedVec,
+};
+pub use pieces::Pieces;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+pub use shared::{ArcChunk, ArcPieces};
+#[cfg(feature = "alloc")]
+pub use sort::sort_chunks_radix;
+pub use sort::{
+    max_chunk_by_key, merge_sorted_chunks, min_chunk_by_key, partition_chunks,
+    select_nth_chunk_unstable_by,
+};
+pub use split::{split_many, split_many_mut};
+pub use stream::ArrayRechunker;
+#[cfg(feature = "alloc")]
+pub use stream::Rechunker;
+pub use strided::{every_nth_chunk, Strided};
+#[cfg(feature = "alloc")]
+pub use wrap::wrap_vec;
+pub use wrap::{as_wrapped, as_wrapped_mut, wrap_chunks, wrap_chunks_mut, FromChunk};
+
+/// Fails to compile, once monomorphized, if `N` is 0.
+///
+/// Every API whose chunk size is a const generic calls this,
+/// so that a zero chunk size is caught at build time rather than at runtime.
+pub(crate) const fn assert_nonzero<const N: usize>() {
+    const { assert!(N != 0, "chunk size must be non-zero") }
+}
+
+/// Stamps out one flavor, shared (`&`) or mutable (`&mut`), of the core reshaping operations:
+/// `as_with_rest`, `as_exact`, `as_exact_truncated`, `regroup`, `as_exact_array` and `as_pages`.
+///
+/// Both flavors share these bodies, which reshape with safe slice methods on top of the casts in [`raw`].
+/// There is no `_uninit` flavor: the element type is generic, so `MaybeUninit<T>` slices chunk the same way,
+/// and only `stream`, `accumulator` and `collect` assume initialization, through [`raw`].
+/// Everything else, like [`get_chunks_mut`] and the split and iterator helpers,
+/// is written by hand on top of these operations.
+macro_rules! flavor {
+    (
+        [$($mut:ident)?] $split_at:ident $first_chunk:ident $flatten:ident $chunks:ident;
+        $(#[$as_with_rest_doc:meta])* fn $as_with_rest:ident;
+        $(#[$as_exact_doc:meta])* fn $as_exact:ident;
+        $(#[$as_exact_truncated_doc:meta])* fn $as_exact_truncated:ident;
+        $(#[$regroup_doc:meta])* fn $regroup:ident;
+        $(#[$as_exact_array_doc:meta])* fn $as_exact_array:ident;
+        $(#[$as_pages_doc:meta])* fn $as_pages:ident;
+    ) => {
+        $(#[$as_with_rest_doc])*
+        pub const fn $as_with_rest<T, const N: usize>(
+            slice: &$($mut)? [T],
+        ) -> (&$($mut)? [[T; N]], &$($mut)? [T]) {
+            assert_nonzero::<N>();
+            let (chunks, rest) = slice.$split_at(slice.len() / N * N);
+            (raw::$chunks(chunks), rest)
+        }
+
+        $(#[$as_exact_doc])*
+        pub const fn $as_exact<T, const N: usize>(slice: &$($mut)? [T]) -> &$($mut)? [[T; N]] {
+            assert!(
+                slice.len().is_multiple_of(N),
+                concat!(
+                    "pieced::",
+                    stringify!($as_exact),
+                    " requires the slice to split exactly into `N`-element chunks",
+                ),
+            );
+            raw::$chunks(slice)
+        }
+
+        $(#[$as_exact_truncated_doc])*
+        pub const fn $as_exact_truncated<T, const N: usize>(
+            slice: &$($mut)? [T],
+        ) -> &$($mut)? [[T; N]] {
+            raw::$chunks(slice)
+        }
+
+        $(#[$regroup_doc])*
+        pub const fn $regroup<T, const A: usize, const B: usize>(
+            chunks: &$($mut)? [[T; A]],
+        ) -> (&$($mut)? [[T; B]], &$($mut)? [T]) {
+            $as_with_rest(chunks.$flatten())
+        }
+
+        $(#[$as_exact_array_doc])*
+        pub const fn $as_exact_array<T, const N: usize, const M: usize>(
+            slice: &$($mut)? [T],
+        ) -> Option<&$($mut)? [[T; N]; M]> {
+            match flat_len::<N>(M) {
+                Some(len) if len == slice.len() => raw::$chunks(slice).$first_chunk(),
+                _ => None,
+            }
+        }
+
+        $(#[$as_pages_doc])*
+        pub fn $as_pages<const PAGE: usize>(
+            bytes: &$($mut)? [u8],
+        ) -> (&$($mut)? [u8], &$($mut)? [[u8; PAGE]], &$($mut)? [u8]) {
+            assert_nonzero::<PAGE>();
+            let misalignment = bytes.as_ptr().addr() % PAGE;
+            let offset = ((PAGE - misalignment) % PAGE).min(bytes.len());
+            let (prefix, aligned) = bytes.$split_at(offset);
+            let (pages, suffix) = $as_with_rest(aligned);
+            (prefix, pages, suffix)
+        }
+    };
+}
+
+flavor! {
+    [] split_at first_chunk as_flattened chunks;
+    /// Splits the slice into a slice of `N`-element arrays,
+    /// starting at the beginning of the slice,
+    /// and a rest slice with length strictly less than `N`.
+    ///
+    /// # Compile-time errors
+    ///
+    /// Fails to compile if `N` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let slice = ['l', 'o', 'r', 'e', 'm'];
+    /// let (chunks, rest) = pieced::as_with_rest(&slice);
+    /// assert_eq!(chunks, &[['l', 'o'], ['r', 'e']]);
+    /// assert_eq!(rest, &['m']);
+    /// ```
+    ///
+    /// If you expect the slice to be an exact multiple, you can combine
+    /// `let`-`else` with an empty slice pattern (or use [`as_exact`]):
+    /// ```
+    /// let slice = ['R', 'u', 's', 't'];
+    /// let (chunks, []) = pieced::as_with_rest(&slice) else {
+    ///     panic!("slice didn't have even length")
+    /// };
+    /// assert_eq!(chunks, &[['R', 'u'], ['s', 't']]);
+    /// ```
+    fn as_with_rest;
+
+    /// Splits the slice into a slice of `N`-element arrays, assuming that there's no remainder.
+    ///
+    /// # Panics
+    ///
+    /// Panics unless the slice splits exactly into `N`-element chunks (aka `slice.len() % N == 0`).
+    ///
+    /// # Compile-time errors
+    ///
+    /// Fails to compile if `N` is 0:
+    /// ```compile_fail
+    /// let chunks: &[[u8; 0]] = pieced::as_exact(&[]);
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let slice: &[char] = &['l', 'o', 'r', 'e', 'm', '!'];
+    /// let chunks: &[[char; 1]] = pieced::as_exact(slice);
+    /// assert_eq!(chunks, &[['l'], ['o'], ['r'], ['e'], ['m'], ['!']]);
+    /// let chunks: &[[char; 3]] = pieced::as_exact(slice);
+    /// assert_eq!(chunks, &[['l', 'o', 'r'], ['e', 'm', '!']]);
+    /// ```
+    fn as_exact;
+
+    /// Splits the slice into a slice of `N`-element arrays, silently dropping the remainder.
+    ///
+    /// This is [`as_with_rest`] without the rest: the last `slice.len() % N` elements are left out.
+    ///
+    /// # Compile-time errors
+    ///
+    /// Fails to compile if `N` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let slice = ['l', 'o', 'r', 'e', 'm'];
+    /// let chunks: &[[char; 2]] = pieced::as_exact_truncated(&slice);
+    /// assert_eq!(chunks, &[['l', 'o'], ['r', 'e']]);
+    /// ```
+    fn as_exact_truncated;
+
+    /// Reinterprets a slice of `A`-element chunks as a slice of `B`-element chunks,
+    /// and a rest slice with length strictly less than `B`.
+    ///
+    /// This is [`as_with_rest`] on the flattened chunks.
+    ///
+    /// # Compile-time errors
+    ///
+    /// Fails to compile if `B` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let pages = [[0u8; 64]; 2];
+    /// let (lines, []) = pieced::regroup::<_, 64, 16>(&pages) else {
+    ///     unreachable!()
+    /// };
+    /// assert_eq!(lines.len(), 8);
+    ///
+    /// let (pairs, rest) = pieced::regroup::<_, 3, 2>(&[[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// assert_eq!(pairs, &[[1, 2], [3, 4], [5, 6], [7, 8]]);
+    /// assert_eq!(rest, &[9]);
+    /// ```
+    fn regroup;
+
+    /// Reinterprets the slice as exactly `M` chunks of `N` elements.
+    ///
+    /// Returns [`None`] unless `slice.len() == N * M`.
+    ///
+    /// # Compile-time errors
+    ///
+    /// Fails to compile if `N` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let slice = [1, 2, 3, 4, 5, 6];
+    /// let table: &[[i32; 3]; 2] = pieced::as_exact_array(&slice).unwrap();
+    /// assert_eq!(table, &[[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(pieced::as_exact_array::<_, 2, 2>(&slice), None);
+    /// ```
+    fn as_exact_array;
+
+    /// Splits the bytes into an unaligned prefix, a slice of `PAGE`-byte pages
+    /// that each start at an address that is a multiple of `PAGE`, and a suffix
+    /// with length strictly less than `PAGE`.
+    ///
+    /// The prefix is shorter than `PAGE`, and holds every byte
+    /// if there isn't a single aligned address in the slice.
+    ///
+    /// # Compile-time errors
+    ///
+    /// Fails to compile if `PAGE` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[repr(align(64))]
+    /// struct Aligned([u8; 256]);
+    /// let buffer = Aligned([0; 256]);
+    ///
+    /// let (prefix, pages, suffix) = pieced::as_pages::<64>(&buffer.0[10..200]);
+    /// assert_eq!((prefix.len(), pages.len(), suffix.len()), (54, 2, 8));
+    /// assert!(pages.iter().all(|page| page.as_ptr() as usize % 64 == 0));
+    /// ```
+    fn as_pages;
+}
+
+flavor! {
+    [mut] split_at_mut first_chunk_mut as_flattened_mut chunks_mut;
+    /// Mutable [`as_with_rest`].
+    ///
+    /// # Compile-time errors
+    ///
+    /// Fails to compile if `N` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut slice = [1, 2, 3, 4, 5];
+    /// let (chunks, rest) = pieced::as_with_rest_mut::<_, 2>(&mut slice);
+    /// chunks.reverse();
+    /// rest[0] = 0;
+    /// assert_eq!(slice, [3, 4, 1, 2, 0]);
+    /// ```
+    ///
+    /// Like every operation, this works on uninitialized memory too:
+    /// ```
+    /// use core::mem::MaybeUninit;
+    /// let mut buffer = [MaybeUninit::<u32>::uninit(); 5];
+    /// let (chunks, _) = pieced::as_with_rest_mut(&mut buffer);
+    /// for (i, [a, b]) in chunks.iter_mut().enumerate() {
+    ///     a.write(i as u32);
+    ///     b.write(!(i as u32));
+    /// }
+    /// // SAFETY: the first four elements were just written.
+    /// assert_eq!(unsafe { buffer[3].assume_init() }, !1);
+    /// ```
+    fn as_with_rest_mut;
+
+    /// Mutable [`as_exact`].
+    ///
+    /// # Panics
+    ///
+    /// Panics unless the slice splits exactly into `N`-element chunks (aka `slice.len() % N == 0`).
+    ///
+    /// # Compile-time errors
+    ///
+    /// Fails to compile if `N` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut slice = [1, 2, 3, 4];
+    /// for [a, b] in pieced::as_exact_mut(&mut slice) {
+    ///     core::mem::swap(a, b);
+    /// }
+    /// assert_eq!(slice, [2, 1, 4, 3]);
+    /// ```
+    fn as_exact_mut;
+
+    /// Mutable [`as_exact_truncated`].
+    ///
+    /// # Compile-time errors
+    ///
+    /// Fails to compile if `N` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut slice = [1, 2, 3, 4, 5];
+    /// for [a, b] in pieced::as_exact_truncated_mut(&mut slice) {
+    ///     core::mem::swap(a, b);
+    /// }
+    /// assert_eq!(slice, [2, 1, 4, 3, 5]);
+    /// ```
+    fn as_exact_truncated_mut;
+
+    /// Mutable [`regroup`].
+    ///
+    /// # Compile-time errors
+    ///
+    /// Fails to compile if `B` is 0.
+    fn regroup_mut;
+
+    /// Mutable [`as_exact_array`].
+    ///
+    /// # Compile-time errors
+    ///
+    /// Fails to compile if `N` is 0.
+    fn as_exact_array_mut;
+
+    /// Mutable [`as_pages`].
+    ///
+    /// # Compile-time errors
+    ///
+    /// Fails to compile if `PAGE` is 0.
+    fn as_pages_mut;
+}
+
+/// Returns mutable references to the `i`th and `j`th `N`-element chunks of the slice at once.
+///
+/// Chunks are counted like [`as_with_rest`] counts them; the remainder is never part of a chunk.
+/// Returns [`None`] if `i == j` or either index is out of bounds.
 ///
-/// # Panics
+/// # Compile-time errors
+///
+/// Fails to compile if `N` is 0.
 ///
-/// Panics if `N` is 0.
 /// # Examples
 ///
 /// ```
-/// let slice = ['l', 'o', 'r', 'e', 'm'];
-/// let (chunks, rest) = pieced::as_with_rest(&slice);
-/// assert_eq!(chunks, &[['l', 'o'], ['r', 'e']]);
-/// assert_eq!(rest, &['m']);
-/// ```
+/// let mut slice = [1, 2, 3, 4, 5, 6, 7];
+/// let (a, b) = pieced::get_chunks_mut::<_, 2>(&mut slice, 2, 0).unwrap();
+/// core::mem::swap(a, b);
+/// assert_eq!(slice, [5, 6, 3, 4, 1, 2, 7]);
 ///
-/// If you expect the slice to be an exact multiple, you can combine
-/// `let`-`else` with an empty slice pattern (or use [`as_exact`]):
+/// assert!(pieced::get_chunks_mut::<_, 2>(&mut slice, 1, 1).is_none());
+/// assert!(pieced::get_chunks_mut::<_, 2>(&mut slice, 0, 3).is_none());
 /// ```
-/// let slice = ['R', 'u', 's', 't'];
-/// let (chunks, []) = pieced::as_with_rest(&slice) else {
-///     panic!("slice didn't have even length")
-/// };
-/// assert_eq!(chunks, &[['R', 'u'], ['s', 't']]);
-/// ```
-pub const fn as_with_rest<T, const N: usize>(slice: &[T]) -> (&[[T; N]], &[T]) {
-    assert!(N != 0, "chunk size must be non-zero");
-    let len = slice.len() / N;
-    let (multiple_of_n, remainder) = slice.split_at(len * N);
-    let new_len = multiple_of_n.len() / N;
-    // SAFETY: We cast a slice of `new_len * N` elements into
-    // a slice of `new_len` many `N` elements chunks.
-    (
-        unsafe { core::slice::from_raw_parts(multiple_of_n.as_ptr().cast(), new_len) },
-        remainder,
-    )
+pub fn get_chunks_mut<T, const N: usize>(
+    slice: &mut [T],
+    i: usize,
+    j: usize,
+) -> Option<(&mut [T; N], &mut [T; N])> {
+    assert_nonzero::<N>();
+    let count = slice.len() / N;
+    if i == j || i >= count || j >= count {
+        return None;
+    }
+    let (head, tail) = slice.split_at_mut(i.max(j) * N);
+    let low = head[i.min(j) * N..].first_chunk_mut()?;
+    let high = tail.first_chunk_mut()?;
+    Some(if i < j { (low, high) } else { (high, low) })
 }
 
-/// Splits the slice into a slice of `N`-element arrays, assuming that there's no remainder.
+/// Returns mutable references to the `N`-element chunks of the slice at all `indices` at once.
 ///
-/// # Panics
+/// Chunks are counted like [`as_with_rest`] counts them; the remainder is never part of a chunk.
+/// Returns [`None`] if any index is repeated or out of bounds.
 ///
-/// Panics when
-/// - The slice splits exactly into `N`-element chunks (aka `self.len() % N == 0`).
-/// - `N != 0`.
+/// # Compile-time errors
+///
+/// Fails to compile if `N` is 0.
 ///
 /// # Examples
 ///
 /// ```
-/// let slice: &[char] = &['l', 'o', 'r', 'e', 'm', '!'];
-/// let chunks: &[[char; 1]] = pieced::as_exact(slice);
-/// assert_eq!(chunks, &[['l'], ['o'], ['r'], ['e'], ['m'], ['!']]);
-/// let chunks: &[[char; 3]] = pieced::as_exact(slice);
-/// assert_eq!(chunks, &[['l', 'o', 'r'], ['e', 'm', '!']]);
+/// let mut records = [0u8; 12];
+/// let [a, b, c] = pieced::get_many_chunks_mut::<_, 3, 3>(&mut records, [3, 0, 1]).unwrap();
+/// a.fill(3);
+/// b.fill(1);
+/// c[0] = 2;
+/// assert_eq!(records, [1, 1, 1, 2, 0, 0, 0, 0, 0, 3, 3, 3]);
+///
+/// assert!(pieced::get_many_chunks_mut::<_, 3, 2>(&mut records, [1, 1]).is_none());
+/// assert!(pieced::get_many_chunks_mut::<_, 3, 1>(&mut records, [4]).is_none());
 /// ```
-pub const fn as_exact<T, const N: usize>(slice: &[T]) -> &[[T; N]] {
-    assert!(
-        N != 0 && slice.len() % N == 0,
-        "pieced::as_exact requires `N != 0` and the slice to split exactly into `N`-element chunks",
-    );
-    let new_len = slice.len() / N;
-    // SAFETY: We cast a slice of `new_len * N` elements into
-    // a slice of `new_len` many `N` elements chunks.
-    unsafe { core::slice::from_raw_parts(slice.as_ptr().cast(), new_len) }
+pub fn get_many_chunks_mut<T, const N: usize, const K: usize>(
+    slice: &mut [T],
+    indices: [usize; K],
+) -> Option<[&mut [T; N]; K]> {
+    as_with_rest_mut(slice).0.get_disjoint_mut(indices).ok()
 }
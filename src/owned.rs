@@ -0,0 +1,446 @@
+#[cfg(feature = "allocator_api")]
+use alloc::alloc::Allocator;
+use alloc::boxed::Box;
+use alloc::vec::{self, Vec};
+use core::iter::FusedIterator;
+
+/// Iterator that moves `N`-element chunks out of a vector.
+///
+/// Created by [`into_pieces`].
+#[derive(Debug, Clone)]
+pub struct IntoPieces<T, const N: usize> {
+    elements: vec::IntoIter<T>,
+}
+
+/// Iterates over the `N`-element chunks of the vector by value, moving the elements out.
+///
+/// The remainder is never yielded; see [`IntoPieces::remainder`] and [`IntoPieces::into_rest`].
+/// Elements that aren't moved out are dropped along with the iterator.
+///
+/// # Compile-time errors
+///
+/// Fails to compile if `N` is 0.
+///
+/// # Examples
+///
+/// ```
+/// let names = vec![String::from("a"), "b".into(), "c".into(), "d".into(), "e".into()];
+/// let mut pairs = pieced::into_pieces::<_, 2>(names);
+/// let [first, second] = pairs.next().unwrap();
+/// assert_eq!(first + &second, "ab");
+/// assert_eq!(pairs.remainder(), ["e"]);
+/// assert_eq!(pairs.into_rest().collect::<Vec<_>>(), ["c", "d", "e"]);
+/// ```
+pub fn into_pieces<T, const N: usize>(vec: Vec<T>) -> IntoPieces<T, N> {
+    crate::assert_nonzero::<N>();
+    IntoPieces {
+        elements: vec.into_iter(),
+    }
+}
+
+impl<T, const N: usize> IntoPieces<T, N> {
+    /// Returns the elements after the last chunk, which the iterator never yields.
+    pub fn remainder(&self) -> &[T] {
+        let rest = self.elements.as_slice();
+        &rest[rest.len() - rest.len() % N..]
+    }
+
+    /// Returns the elements that haven't been moved out yet:
+    /// the chunks that haven't been yielded, followed by the remainder.
+    pub fn into_rest(self) -> vec::IntoIter<T> {
+        self.elements
+    }
+}
+
+impl<T, const N: usize> Iterator for IntoPieces<T, N> {
+    type Item = [T; N];
+
+    fn next(&mut self) -> Option<[T; N]> {
+        if self.elements.len() < N {
+            return None;
+        }
+        crate::collect::next_chunk(&mut self.elements).ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.elements.len() / N;
+        (len, Some(len))
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for IntoPieces<T, N> {}
+impl<T, const N: usize> FusedIterator for IntoPieces<T, N> {}
+
+/// Takes `Vec`s and `Box`es apart into raw parts, and back together.
+#[cfg(not(feature = "allocator_api"))]
+pub(crate) mod parts {
+    use alloc::boxed::Box;
+    use alloc::vec::Vec;
+    use core::mem::ManuallyDrop;
+
+    pub fn vec_into_parts<T>(vec: Vec<T>) -> (*mut T, usize, usize, ()) {
+        let mut vec = ManuallyDrop::new(vec);
+        (vec.as_mut_ptr(), vec.len(), vec.capacity(), ())
+    }
+
+    /// # Safety
+    ///
+    /// See [`Vec::from_raw_parts`].
+    pub unsafe fn vec_from_parts<T>(ptr: *mut T, len: usize, cap: usize, (): ()) -> Vec<T> {
+        unsafe { Vec::from_raw_parts(ptr, len, cap) }
+    }
+
+    pub fn box_into_parts<T>(boxed: Box<[T]>) -> (*mut [T], ()) {
+        (Box::into_raw(boxed), ())
+    }
+
+    /// # Safety
+    ///
+    /// See [`Box::from_raw`].
+    pub unsafe fn box_from_parts<T>(ptr: *mut [T], (): ()) -> Box<[T]> {
+        unsafe { Box::from_raw(ptr) }
+    }
+}
+
+/// Takes `Vec`s and `Box`es apart into raw parts, and back together, in any allocator.
+#[cfg(feature = "allocator_api")]
+pub(crate) mod parts {
+    use alloc::alloc::Allocator;
+    use alloc::boxed::Box;
+    use alloc::vec::Vec;
+
+    pub fn vec_into_parts<T, A: Allocator>(vec: Vec<T, A>) -> (*mut T, usize, usize, A) {
+        vec.into_raw_parts_with_alloc()
+    }
+
+    /// # Safety
+    ///
+    /// See [`Vec::from_raw_parts_in`].
+    pub unsafe fn vec_from_parts<T, A: Allocator>(
+        ptr: *mut T,
+        len: usize,
+        cap: usize,
+        alloc: A,
+    ) -> Vec<T, A> {
+        unsafe { Vec::from_raw_parts_in(ptr, len, cap, alloc) }
+    }
+
+    pub fn box_into_parts<T, A: Allocator>(boxed: Box<[T], A>) -> (*mut [T], A) {
+        Box::into_raw_with_allocator(boxed)
+    }
+
+    /// # Safety
+    ///
+    /// See [`Box::from_raw_in`].
+    pub unsafe fn box_from_parts<T, A: Allocator>(ptr: *mut [T], alloc: A) -> Box<[T], A> {
+        unsafe { Box::from_raw_in(ptr, alloc) }
+    }
+}
+
+/// Defines the owned conversions, generic over the allocator `$A` if one is given.
+macro_rules! conversions {
+    ($($A:ident)?) => {
+        /// Converts a vector into a vector of `N`-element chunks, without copying the elements.
+        ///
+        /// If the capacity isn't a multiple of `N`, the vector is shrunk to fit first, which may reallocate.
+        ///
+        /// # Errors
+        ///
+        /// Returns the vector back if its length isn't a multiple of `N`.
+        ///
+        /// # Compile-time errors
+        ///
+        /// Fails to compile if `N` is 0.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// let chunks: Vec<[u8; 2]> = pieced::into_exact_vec(vec![1, 2, 3, 4]).unwrap();
+        /// assert_eq!(chunks, [[1, 2], [3, 4]]);
+        /// let odd: Result<Vec<[u8; 2]>, _> = pieced::into_exact_vec(vec![1, 2, 3]);
+        /// assert_eq!(odd, Err(vec![1, 2, 3]));
+        /// ```
+        pub fn into_exact_vec<T, const N: usize $(, $A: Allocator)?>(
+            vec: Vec<T $(, $A)?>,
+        ) -> Result<Vec<[T; N] $(, $A)?>, Vec<T $(, $A)?>> {
+            crate::assert_nonzero::<N>();
+            if !vec.len().is_multiple_of(N) {
+                return Err(vec);
+            }
+            let vec = if vec.capacity().is_multiple_of(N) {
+                vec
+            } else {
+                Vec::from(vec.into_boxed_slice())
+            };
+            let (ptr, len, cap, alloc) = parts::vec_into_parts(vec);
+            // SAFETY: `len` and `cap` are multiples of `N`,
+            // so the allocation holds exactly `cap / N` chunks, `len / N` of which are initialized.
+            Ok(unsafe { parts::vec_from_parts(ptr.cast(), len / N, cap / N, alloc) })
+        }
+
+        /// Converts a vector of `N`-element chunks into a vector of their elements, without copying.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `vec.len() * N` overflows `usize`, which can only happen for zero-sized `T`.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// assert_eq!(pieced::flatten_vec(vec![[1, 2], [3, 4]]), [1, 2, 3, 4]);
+        /// ```
+        pub fn flatten_vec<T, const N: usize $(, $A: Allocator)?>(
+            vec: Vec<[T; N] $(, $A)?>,
+        ) -> Vec<T $(, $A)?> {
+            let (ptr, len, cap, alloc) = parts::vec_into_parts(vec);
+            let len = crate::flat_len::<N>(len).expect("flattened length overflows usize");
+            // SAFETY: `cap` chunks are `cap * N` elements, which only overflows for zero-sized `T`,
+            // whose capacity is meaningless.
+            unsafe { parts::vec_from_parts(ptr.cast(), len, cap.saturating_mul(N), alloc) }
+        }
+
+        /// Converts a boxed slice into a boxed slice of `N`-element chunks, without copying.
+        ///
+        /// # Errors
+        ///
+        /// Returns the boxed slice back if its length isn't a multiple of `N`.
+        ///
+        /// # Compile-time errors
+        ///
+        /// Fails to compile if `N` is 0.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// let chunks: Box<[[u8; 3]]> = pieced::into_exact_box(Box::new([1, 2, 3, 4, 5, 6])).unwrap();
+        /// assert_eq!(*chunks, [[1, 2, 3], [4, 5, 6]]);
+        /// ```
+        pub fn into_exact_box<T, const N: usize $(, $A: Allocator)?>(
+            boxed: Box<[T] $(, $A)?>,
+        ) -> Result<Box<[[T; N]] $(, $A)?>, Box<[T] $(, $A)?>> {
+            crate::assert_nonzero::<N>();
+            let len = boxed.len();
+            if !len.is_multiple_of(N) {
+                return Err(boxed);
+            }
+            let (ptr, alloc) = parts::box_into_parts(boxed);
+            let ptr = core::ptr::slice_from_raw_parts_mut(ptr.cast::<[T; N]>(), len / N);
+            // SAFETY: `len / N` chunks take up exactly the same memory as `len` elements.
+            Ok(unsafe { parts::box_from_parts(ptr, alloc) })
+        }
+
+        /// Converts a boxed slice of `N`-element chunks into a boxed slice of their elements, without copying.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `boxed.len() * N` overflows `usize`, which can only happen for zero-sized `T`.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// let flat = pieced::flatten_box(Box::new([[1, 2], [3, 4]]));
+        /// assert_eq!(*flat, [1, 2, 3, 4]);
+        /// ```
+        pub fn flatten_box<T, const N: usize $(, $A: Allocator)?>(
+            boxed: Box<[[T; N]] $(, $A)?>,
+        ) -> Box<[T] $(, $A)?> {
+            let len = crate::flat_len::<N>(boxed.len()).expect("flattened length overflows usize");
+            let (ptr, alloc) = parts::box_into_parts(boxed);
+            let ptr = core::ptr::slice_from_raw_parts_mut(ptr.cast::<T>(), len);
+            // SAFETY: `len` elements take up exactly the same memory as `len / N` chunks.
+            unsafe { parts::box_from_parts(ptr, alloc) }
+        }
+    };
+}
+
+#[cfg(not(feature = "allocator_api"))]
+conversions!();
+#[cfg(feature = "allocator_api")]
+conversions!(A);
+
+/// An owned, growable slice of `N`-element chunks: a thin wrapper over `Vec<[T; N]>`.
+///
+/// # Examples
+///
+/// ```
+/// use pieced::PiecedVec;
+/// let mut table: PiecedVec<u8, 2> = [*b"ab", *b"cd"].into_iter().collect();
+/// table.push_flat(b"ef");
+/// table.extend([*b"gh"]);
+/// assert_eq!(table.len(), 4);
+/// assert_eq!(table.as_flat(), b"abcdefgh");
+/// assert_eq!(table[1], *b"cd");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PiecedVec<T, const N: usize> {
+    chunks: Vec<[T; N]>,
+}
+
+impl<T, const N: usize> PiecedVec<T, N> {
+    /// Creates an empty vector.
+    pub const fn new() -> Self {
+        Self { chunks: Vec::new() }
+    }
+
+    /// Creates an empty vector with room for at least `chunks` chunks.
+    pub fn with_capacity(chunks: usize) -> Self {
+        Self {
+            chunks: Vec::with_capacity(chunks),
+        }
+    }
+
+    /// Appends a chunk.
+    pub fn push(&mut self, chunk: [T; N]) {
+        self.chunks.push(chunk);
+    }
+
+    /// Returns the chunks as a [`Pieces`](crate::Pieces) view.
+    pub fn as_pieces(&self) -> &crate::Pieces<T, N> {
+        crate::Pieces::new(&self.chunks)
+    }
+
+    /// Returns the chunks as a mutable [`Pieces`](crate::Pieces) view.
+    pub fn as_pieces_mut(&mut self) -> &mut crate::Pieces<T, N> {
+        crate::Pieces::new_mut(&mut self.chunks)
+    }
+
+    /// Returns the elements of all chunks as one slice.
+    pub fn as_flat(&self) -> &[T] {
+        self.chunks.as_flattened()
+    }
+
+    /// Returns the elements of all chunks as one mutable slice.
+    pub fn as_flat_mut(&mut self) -> &mut [T] {
+        self.chunks.as_flattened_mut()
+    }
+
+    /// Returns the underlying vector of chunks.
+    pub fn into_inner(self) -> Vec<[T; N]> {
+        self.chunks
+    }
+
+    /// Returns the elements of all chunks as one vector, without copying.
+    pub fn into_flat(self) -> Vec<T> {
+        flatten_vec(self.chunks)
+    }
+}
+
+impl<T: Clone, const N: usize> PiecedVec<T, N> {
+    /// Appends a copy of `chunk`.
+    pub fn push_flat(&mut self, chunk: &[T; N]) {
+        self.chunks.push(chunk.clone());
+    }
+}
+
+impl<T, const N: usize> Default for PiecedVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> core::ops::Deref for PiecedVec<T, N> {
+    type Target = [[T; N]];
+
+    fn deref(&self) -> &[[T; N]] {
+        &self.chunks
+    }
+}
+
+impl<T, const N: usize> core::ops::DerefMut for PiecedVec<T, N> {
+    fn deref_mut(&mut self) -> &mut [[T; N]] {
+        &mut self.chunks
+    }
+}
+
+impl<T, const N: usize> AsRef<[[T; N]]> for PiecedVec<T, N> {
+    fn as_ref(&self) -> &[[T; N]] {
+        &self.chunks
+    }
+}
+
+impl<T, const N: usize> AsMut<[[T; N]]> for PiecedVec<T, N> {
+    fn as_mut(&mut self) -> &mut [[T; N]] {
+        &mut self.chunks
+    }
+}
+
+impl<T, const N: usize> FromIterator<[T; N]> for PiecedVec<T, N> {
+    fn from_iter<I: IntoIterator<Item = [T; N]>>(iter: I) -> Self {
+        Self {
+            chunks: Vec::from_iter(iter),
+        }
+    }
+}
+
+impl<T, const N: usize> Extend<[T; N]> for PiecedVec<T, N> {
+    fn extend<I: IntoIterator<Item = [T; N]>>(&mut self, iter: I) {
+        self.chunks.extend(iter);
+    }
+}
+
+impl<'a, T: Copy + 'a, const N: usize> Extend<&'a [T; N]> for PiecedVec<T, N> {
+    fn extend<I: IntoIterator<Item = &'a [T; N]>>(&mut self, iter: I) {
+        self.chunks.extend(iter);
+    }
+}
+
+impl<T, const N: usize> IntoIterator for PiecedVec<T, N> {
+    type Item = [T; N];
+    type IntoIter = vec::IntoIter<[T; N]>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.chunks.into_iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a PiecedVec<T, N> {
+    type Item = &'a [T; N];
+    type IntoIter = core::slice::Iter<'a, [T; N]>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.chunks.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut PiecedVec<T, N> {
+    type Item = &'a mut [T; N];
+    type IntoIter = core::slice::IterMut<'a, [T; N]>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.chunks.iter_mut()
+    }
+}
+
+impl<T, const N: usize> From<Vec<[T; N]>> for PiecedVec<T, N> {
+    fn from(chunks: Vec<[T; N]>) -> Self {
+        Self { chunks }
+    }
+}
+
+impl<T, const N: usize> From<PiecedVec<T, N>> for Vec<[T; N]> {
+    fn from(chunks: PiecedVec<T, N>) -> Self {
+        chunks.chunks
+    }
+}
+
+/// Fallible [`into_exact_vec`].
+///
+/// # Compile-time errors
+///
+/// Fails to compile if `N` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use pieced::PiecedVec;
+/// let table = PiecedVec::<_, 2>::try_from(vec![1, 2, 3, 4]).unwrap();
+/// assert_eq!(table.as_flat(), [1, 2, 3, 4]);
+/// assert_eq!(PiecedVec::<_, 2>::try_from(vec![1, 2, 3]), Err(vec![1, 2, 3]));
+/// ```
+impl<T, const N: usize> TryFrom<Vec<T>> for PiecedVec<T, N> {
+    type Error = Vec<T>;
+
+    fn try_from(vec: Vec<T>) -> Result<Self, Vec<T>> {
+        into_exact_vec(vec).map(Self::from)
+    }
+}
@@ -0,0 +1,244 @@
+use core::ops::{
+    Deref, DerefMut, Index, IndexMut, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo,
+    RangeToInclusive,
+};
+use core::slice::SliceIndex;
+
+use crate::PiecedError;
+
+/// A slice of `N`-element chunks.
+///
+/// This is `[[T; N]]` (which it derefs to) with chunk-granular sub-views:
+/// indexing with a range, [`get`](Pieces::get) and [`split_at_chunks`](Pieces::split_at_chunks)
+/// return `Pieces` again, rather than a bare slice.
+///
+/// # Examples
+///
+/// ```
+/// use pieced::Pieces;
+/// let pieces = Pieces::new(pieced::as_exact::<_, 2>(&[1, 2, 3, 4, 5, 6]));
+/// let tail: &Pieces<_, 2> = &pieces[1..];
+/// assert_eq!(tail.as_flat(), &[3, 4, 5, 6]);
+/// assert_eq!(tail[0], [3, 4]);
+/// assert_eq!(tail.len(), 2);
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Pieces<T, const N: usize>([[T; N]]);
+
+impl<T, const N: usize> Pieces<T, N> {
+    /// Wraps a slice of chunks.
+    pub const fn new(chunks: &[[T; N]]) -> &Self {
+        crate::raw::pieces(chunks)
+    }
+
+    /// Wraps a mutable slice of chunks.
+    pub fn new_mut(chunks: &mut [[T; N]]) -> &mut Self {
+        crate::raw::pieces_mut(chunks)
+    }
+
+    /// Returns the chunks as a slice.
+    pub const fn as_chunks(&self) -> &[[T; N]] {
+        &self.0
+    }
+
+    /// Returns the chunks as a mutable slice.
+    pub fn as_chunks_mut(&mut self) -> &mut [[T; N]] {
+        &mut self.0
+    }
+
+    /// Returns the elements of all chunks as one slice.
+    pub const fn as_flat(&self) -> &[T] {
+        self.0.as_flattened()
+    }
+
+    /// Returns the elements of all chunks as one mutable slice.
+    pub fn as_flat_mut(&mut self) -> &mut [T] {
+        self.0.as_flattened_mut()
+    }
+
+    /// Returns the chunks in `range`, or [`None`] if it's out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let pieces = pieced::Pieces::new(&[[1, 2], [3, 4], [5, 6]]);
+    /// assert_eq!(pieces.get(1..).unwrap().as_flat(), &[3, 4, 5, 6]);
+    /// assert!(pieces.get(2..4).is_none());
+    /// ```
+    pub fn get<I: SliceIndex<[[T; N]], Output = [[T; N]]>>(&self, range: I) -> Option<&Self> {
+        self.0.get(range).map(Self::new)
+    }
+
+    /// Returns the chunks in `range` mutably, or [`None`] if it's out of bounds.
+    pub fn get_mut<I: SliceIndex<[[T; N]], Output = [[T; N]]>>(
+        &mut self,
+        range: I,
+    ) -> Option<&mut Self> {
+        self.0.get_mut(range).map(Self::new_mut)
+    }
+
+    /// Divides the chunks in two at chunk index `mid`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let pieces = pieced::Pieces::new(&[[1, 2], [3, 4], [5, 6]]);
+    /// let (head, tail) = pieces.split_at_chunks(1);
+    /// assert_eq!((head.as_flat(), tail.as_flat()), (&[1, 2][..], &[3, 4, 5, 6][..]));
+    /// ```
+    pub const fn split_at_chunks(&self, mid: usize) -> (&Self, &Self) {
+        let (head, tail) = self.0.split_at(mid);
+        (Self::new(head), Self::new(tail))
+    }
+
+    /// Divides the chunks in two mutable halves at chunk index `mid`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > self.len()`.
+    pub fn split_at_chunks_mut(&mut self, mid: usize) -> (&mut Self, &mut Self) {
+        let (head, tail) = self.0.split_at_mut(mid);
+        (Self::new_mut(head), Self::new_mut(tail))
+    }
+}
+
+impl<T, const N: usize> Deref for Pieces<T, N> {
+    type Target = [[T; N]];
+
+    fn deref(&self) -> &[[T; N]] {
+        &self.0
+    }
+}
+
+impl<T, const N: usize> DerefMut for Pieces<T, N> {
+    fn deref_mut(&mut self) -> &mut [[T; N]] {
+        &mut self.0
+    }
+}
+
+impl<T, const N: usize> AsRef<[[T; N]]> for Pieces<T, N> {
+    fn as_ref(&self) -> &[[T; N]] {
+        &self.0
+    }
+}
+
+impl<T, const N: usize> AsMut<[[T; N]]> for Pieces<T, N> {
+    fn as_mut(&mut self) -> &mut [[T; N]] {
+        &mut self.0
+    }
+}
+
+impl<'a, T, const N: usize> From<&'a [[T; N]]> for &'a Pieces<T, N> {
+    fn from(chunks: &'a [[T; N]]) -> Self {
+        Pieces::new(chunks)
+    }
+}
+
+impl<'a, T, const N: usize> From<&'a mut [[T; N]]> for &'a mut Pieces<T, N> {
+    fn from(chunks: &'a mut [[T; N]]) -> Self {
+        Pieces::new_mut(chunks)
+    }
+}
+
+/// Fallible [`as_exact`](crate::as_exact).
+///
+/// # Compile-time errors
+///
+/// Fails to compile if `N` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use pieced::{Pieces, PiecedError};
+/// fn parse(bytes: &[u8]) -> Result<usize, PiecedError> {
+///     let records: &Pieces<u8, 4> = bytes.try_into()?;
+///     Ok(records.len())
+/// }
+/// assert_eq!(parse(b"abcdefgh"), Ok(2));
+/// assert_eq!(parse(b"abcdefg"), Err(PiecedError::Remainder { len: 7, rest: 3 }));
+/// ```
+impl<'a, T, const N: usize> TryFrom<&'a [T]> for &'a Pieces<T, N> {
+    type Error = PiecedError;
+
+    fn try_from(slice: &'a [T]) -> Result<Self, PiecedError> {
+        match crate::as_with_rest(slice) {
+            (chunks, []) => Ok(Pieces::new(chunks)),
+            (_, rest) => Err(PiecedError::Remainder {
+                len: slice.len(),
+                rest: rest.len(),
+            }),
+        }
+    }
+}
+
+/// Fallible mutable [`as_exact`](crate::as_exact).
+///
+/// # Compile-time errors
+///
+/// Fails to compile if `N` is 0.
+impl<'a, T, const N: usize> TryFrom<&'a mut [T]> for &'a mut Pieces<T, N> {
+    type Error = PiecedError;
+
+    fn try_from(slice: &'a mut [T]) -> Result<Self, PiecedError> {
+        let len = slice.len();
+        match crate::as_with_rest_mut(slice) {
+            (chunks, []) => Ok(Pieces::new_mut(chunks)),
+            (_, rest) => Err(PiecedError::Remainder {
+                len,
+                rest: rest.len(),
+            }),
+        }
+    }
+}
+
+impl<T, const N: usize> Default for &Pieces<T, N> {
+    fn default() -> Self {
+        Pieces::new(&[])
+    }
+}
+
+impl<T, const N: usize> Index<usize> for Pieces<T, N> {
+    type Output = [T; N];
+
+    fn index(&self, index: usize) -> &[T; N] {
+        &self.0[index]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for Pieces<T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut [T; N] {
+        &mut self.0[index]
+    }
+}
+
+macro_rules! index_range {
+    ($($range:ty),+ $(,)?) => {$(
+        impl<T, const N: usize> Index<$range> for Pieces<T, N> {
+            type Output = Self;
+
+            fn index(&self, range: $range) -> &Self {
+                Self::new(&self.0[range])
+            }
+        }
+
+        impl<T, const N: usize> IndexMut<$range> for Pieces<T, N> {
+            fn index_mut(&mut self, range: $range) -> &mut Self {
+                Self::new_mut(&mut self.0[range])
+            }
+        }
+    )+};
+}
+
+index_range!(
+    Range<usize>,
+    RangeFrom<usize>,
+    RangeTo<usize>,
+    RangeInclusive<usize>,
+    RangeToInclusive<usize>,
+    RangeFull,
+);
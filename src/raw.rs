@@ -0,0 +1,125 @@
+//! The casts every borrowed chunk view in the crate is built from.
+//!
+//! This is the only place that reinterprets borrowed elements as chunks,
+//! uninitialized slots as initialized ones, or slices as their wrappers;
+//! the operations on top of it reshape with safe slice methods.
+//! (The owned `Vec`/`Box` conversions take allocations apart and back together in `owned`'s `parts`.)
+
+use core::mem::MaybeUninit;
+
+use crate::Pieces;
+
+/// Views the slice as `slice.len() / N` chunks, leaving out the remainder.
+pub(crate) const fn chunks<T, const N: usize>(slice: &[T]) -> &[[T; N]] {
+    crate::assert_nonzero::<N>();
+    // SAFETY: `[T; N]` is `N` consecutive `T`s with the alignment of `T`,
+    // so `len / N` chunks cover the first `len / N * N <= len` elements of `slice`,
+    // borrowed for the same lifetime.
+    unsafe { core::slice::from_raw_parts(slice.as_ptr().cast(), slice.len() / N) }
+}
+
+/// Views the slice as `slice.len() / N` mutable chunks, leaving out the remainder.
+pub(crate) const fn chunks_mut<T, const N: usize>(slice: &mut [T]) -> &mut [[T; N]] {
+    crate::assert_nonzero::<N>();
+    // SAFETY: see `chunks`; the chunks borrow `slice` exclusively, just like it was.
+    unsafe { core::slice::from_raw_parts_mut(slice.as_mut_ptr().cast(), slice.len() / N) }
+}
+
+/// Reinterprets a slice of `T`s as a slice of `U`s.
+///
+/// # Safety
+///
+/// `U` must have the same size as `T`, and no stricter alignment,
+/// and every valid `T` must be a valid `U`;
+/// as is the case for a `repr(transparent)` wrapper `U` around `T`.
+pub(crate) const unsafe fn cast_slice<T, U>(slice: &[T]) -> &[U] {
+    // SAFETY: upheld by the caller.
+    unsafe { core::slice::from_raw_parts(slice.as_ptr().cast(), slice.len()) }
+}
+
+/// Reinterprets a mutable slice of `T`s as a mutable slice of `U`s.
+///
+/// # Safety
+///
+/// As for [`cast_slice`], and also the other way around:
+/// every valid `U` must be a valid `T`, since the `U`s can be written through.
+pub(crate) const unsafe fn cast_slice_mut<T, U>(slice: &mut [T]) -> &mut [U] {
+    // SAFETY: upheld by the caller.
+    unsafe { core::slice::from_raw_parts_mut(slice.as_mut_ptr().cast(), slice.len()) }
+}
+
+/// Wraps a slice of chunks in a [`Pieces`].
+pub(crate) const fn pieces<T, const N: usize>(chunks: &[[T; N]]) -> &Pieces<T, N> {
+    // SAFETY: `Pieces<T, N>` is a `repr(transparent)` wrapper around `[[T; N]]`.
+    unsafe { &*(chunks as *const [[T; N]] as *const Pieces<T, N>) }
+}
+
+/// Wraps a mutable slice of chunks in a [`Pieces`].
+pub(crate) const fn pieces_mut<T, const N: usize>(chunks: &mut [[T; N]]) -> &mut Pieces<T, N> {
+    // SAFETY: see `pieces`.
+    unsafe { &mut *(chunks as *mut [[T; N]] as *mut Pieces<T, N>) }
+}
+
+/// Views initialized slots as the elements they hold.
+///
+/// # Safety
+///
+/// Every slot must be initialized.
+pub(crate) const unsafe fn assume_init<T>(slots: &[MaybeUninit<T>]) -> &[T] {
+    // SAFETY: `MaybeUninit<T>` has the layout of `T`, and the caller guarantees initialization.
+    unsafe { core::slice::from_raw_parts(slots.as_ptr().cast(), slots.len()) }
+}
+
+/// Views initialized slots as the mutable elements they hold.
+///
+/// # Safety
+///
+/// Every slot must be initialized.
+pub(crate) const unsafe fn assume_init_mut<T>(slots: &mut [MaybeUninit<T>]) -> &mut [T] {
+    // SAFETY: see `assume_init`; the elements borrow `slots` exclusively,
+    // and can only be written with initialized values.
+    unsafe { core::slice::from_raw_parts_mut(slots.as_mut_ptr().cast(), slots.len()) }
+}
+
+/// Views initialized chunk slots as the chunks they hold.
+///
+/// # Safety
+///
+/// Every element of every slot must be initialized.
+pub(crate) const unsafe fn assume_init_chunks<T, const N: usize>(
+    slots: &[[MaybeUninit<T>; N]],
+) -> &[[T; N]] {
+    // SAFETY: `[MaybeUninit<T>; N]` has the layout of `[T; N]`,
+    // and the caller guarantees initialization.
+    unsafe { core::slice::from_raw_parts(slots.as_ptr().cast(), slots.len()) }
+}
+
+/// Views initialized chunk slots as the mutable chunks they hold.
+///
+/// # Safety
+///
+/// Every element of every slot must be initialized.
+pub(crate) const unsafe fn assume_init_chunks_mut<T, const N: usize>(
+    slots: &mut [[MaybeUninit<T>; N]],
+) -> &mut [[T; N]] {
+    // SAFETY: see `assume_init_chunks` and `assume_init_mut`.
+    unsafe { core::slice::from_raw_parts_mut(slots.as_mut_ptr().cast(), slots.len()) }
+}
+
+/// Moves the chunk out of initialized slots.
+///
+/// # Safety
+///
+/// Every element of `slot` must be initialized,
+/// and must be treated as uninitialized afterwards, since the chunk now owns them.
+pub(crate) const unsafe fn read_chunk<T, const N: usize>(slot: &[MaybeUninit<T>; N]) -> [T; N] {
+    // SAFETY: `[MaybeUninit<T>; N]` has the layout of `[T; N]`,
+    // and the caller guarantees initialization and gives up ownership.
+    unsafe { slot.as_ptr().cast::<[T; N]>().read() }
+}
+
+/// Moves `chunk` into the slots, without dropping whatever they held.
+pub(crate) const fn write_chunk<T, const N: usize>(slot: &mut [MaybeUninit<T>; N], chunk: [T; N]) {
+    // SAFETY: `[MaybeUninit<T>; N]` has the layout of `[T; N]`, and any bits are valid for it.
+    unsafe { slot.as_mut_ptr().cast::<[T; N]>().write(chunk) }
+}
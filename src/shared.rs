@@ -0,0 +1,201 @@
+use alloc::sync::Arc;
+use core::fmt;
+use core::ops::{Bound, Deref, Range, RangeBounds};
+
+/// A range of `N`-element chunks of a reference-counted slice, which [`chunk`](ArcPieces::chunk)
+/// hands out one at a time, and [`slice`](ArcPieces::slice) narrows, without copying.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+/// use pieced::ArcPieces;
+/// let records = ArcPieces::<u8, 4>::new(Arc::from(*b"rec0rec1rec2!"));
+/// assert_eq!(records.len(), 3);
+/// assert_eq!(records.remainder(), b"!");
+///
+/// let workers: Vec<_> = (0..records.len())
+///     .map(|i| {
+///         let record = records.chunk(i).unwrap();
+///         std::thread::spawn(move || record[3])
+///     })
+///     .collect();
+/// drop(records);
+/// let ids: Vec<_> = workers.into_iter().map(|w| w.join().unwrap()).collect();
+/// assert_eq!(ids, *b"012");
+/// ```
+///
+/// Each worker can be handed a whole batch of records instead:
+/// ```
+/// use std::sync::Arc;
+/// use pieced::ArcPieces;
+/// let records = ArcPieces::<u8, 2>::new(Arc::from(*b"a0a1b0b1b2c0"));
+/// let batches = [0..2, 2..5, 5..6].map(|range| records.slice(range).unwrap());
+/// assert_eq!(batches[1].as_chunks(), [*b"b0", *b"b1", *b"b2"]);
+/// assert_eq!(batches[1].chunk(0).unwrap().offset(), 4);
+/// assert_eq!(batches[0].remainder(), b"");
+///
+/// let workers = batches.map(|batch| std::thread::spawn(move || batch.len()));
+/// assert_eq!(workers.map(|w| w.join().unwrap()), [2, 3, 1]);
+/// assert!(records.slice(4..7).is_none());
+/// ```
+pub struct ArcPieces<T, const N: usize> {
+    data: Arc<[T]>,
+    /// The chunks of `data`, by index, that this covers.
+    chunks: Range<usize>,
+}
+
+impl<T, const N: usize> ArcPieces<T, N> {
+    /// Views every complete `N`-element chunk of the slice.
+    ///
+    /// # Compile-time errors
+    ///
+    /// Fails to compile if `N` is 0.
+    pub fn new(data: Arc<[T]>) -> Self {
+        let chunks = 0..crate::max_chunks_in::<N>(data.len());
+        Self { data, chunks }
+    }
+
+    /// Narrows the view to the chunks in `range`, which is relative to this view,
+    /// or returns [`None`] if `range` is out of bounds.
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> Option<Self> {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1)?,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1)?,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len(),
+        };
+        (start <= end && end <= self.len()).then(|| Self {
+            data: Arc::clone(&self.data),
+            chunks: self.chunks.start + start..self.chunks.start + end,
+        })
+    }
+
+    /// Returns the number of chunks.
+    pub fn len(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Returns `true` if there are no chunks.
+    pub fn is_empty(&self) -> bool {
+        self.chunks.is_empty()
+    }
+
+    /// Returns the chunks.
+    pub fn as_chunks(&self) -> &[[T; N]] {
+        &crate::as_exact_truncated(&self.data)[self.chunks.clone()]
+    }
+
+    /// Returns the elements of the backing slice after the last complete chunk,
+    /// if this view reaches that chunk, or nothing otherwise.
+    pub fn remainder(&self) -> &[T] {
+        let rest = &self.data[self.chunks.end * N..];
+        if rest.len() < N {
+            rest
+        } else {
+            &[]
+        }
+    }
+
+    /// Returns the backing slice.
+    pub const fn as_arc(&self) -> &Arc<[T]> {
+        &self.data
+    }
+
+    /// Returns a handle to the `index`-th chunk of the view, which keeps the backing slice alive,
+    /// or [`None`] if it's out of bounds.
+    pub fn chunk(&self, index: usize) -> Option<ArcChunk<T, N>> {
+        (index < self.len()).then(|| ArcChunk {
+            data: Arc::clone(&self.data),
+            offset: (self.chunks.start + index) * N,
+        })
+    }
+}
+
+impl<T, const N: usize> Clone for ArcPieces<T, N> {
+    fn clone(&self) -> Self {
+        Self {
+            data: Arc::clone(&self.data),
+            chunks: self.chunks.clone(),
+        }
+    }
+}
+
+impl<T, const N: usize> Deref for ArcPieces<T, N> {
+    type Target = [[T; N]];
+
+    fn deref(&self) -> &[[T; N]] {
+        self.as_chunks()
+    }
+}
+
+impl<T, const N: usize> AsRef<[[T; N]]> for ArcPieces<T, N> {
+    fn as_ref(&self) -> &[[T; N]] {
+        self.as_chunks()
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for ArcPieces<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArcPieces")
+            .field("chunks", &self.as_chunks())
+            .field("remainder", &self.remainder())
+            .finish()
+    }
+}
+
+/// A handle to one chunk of an [`ArcPieces`], which keeps the backing slice alive.
+///
+/// Created by [`ArcPieces::chunk`].
+pub struct ArcChunk<T, const N: usize> {
+    data: Arc<[T]>,
+    /// `data[offset..offset + N]` is the chunk.
+    offset: usize,
+}
+
+impl<T, const N: usize> ArcChunk<T, N> {
+    /// Returns the chunk.
+    pub fn get(&self) -> &[T; N] {
+        self.data[self.offset..]
+            .first_chunk()
+            .expect("`ArcChunk` offsets are in bounds")
+    }
+
+    /// Returns the index of the chunk's first element in the backing slice.
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl<T, const N: usize> Clone for ArcChunk<T, N> {
+    fn clone(&self) -> Self {
+        Self {
+            data: Arc::clone(&self.data),
+            offset: self.offset,
+        }
+    }
+}
+
+impl<T, const N: usize> Deref for ArcChunk<T, N> {
+    type Target = [T; N];
+
+    fn deref(&self) -> &[T; N] {
+        self.get()
+    }
+}
+
+impl<T, const N: usize> AsRef<[T; N]> for ArcChunk<T, N> {
+    fn as_ref(&self) -> &[T; N] {
+        self.get()
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for ArcChunk<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ArcChunk").field(self.get()).finish()
+    }
+}
@@ -0,0 +1,190 @@
+#[cfg(feature = "alloc")]
+use alloc::vec;
+use core::cmp::Ordering;
+
+use crate::PiecedError;
+
+/// Reorders the chunks so that all chunks satisfying `pred` come first,
+/// and returns how many there are.
+///
+/// Chunks satisfying `pred` keep their relative order; the others may not.
+///
+/// # Examples
+///
+/// ```
+/// let mut records = [[1, 0], [0, 9], [2, 0], [0, 8], [3, 0]];
+/// let valid = pieced::partition_chunks(&mut records, |[tag, _]| *tag != 0);
+/// assert_eq!(valid, 3);
+/// assert_eq!(records[..valid], [[1, 0], [2, 0], [3, 0]]);
+/// ```
+pub fn partition_chunks<T, const N: usize>(
+    chunks: &mut [[T; N]],
+    mut pred: impl FnMut(&[T; N]) -> bool,
+) -> usize {
+    let mut split = 0;
+    for i in 0..chunks.len() {
+        if pred(&chunks[i]) {
+            chunks.swap(split, i);
+            split += 1;
+        }
+    }
+    split
+}
+
+/// Merges two runs of chunks, each sorted by `cmp`, into `out`.
+///
+/// The merge is stable: of two equal chunks, the one from `a` comes first.
+///
+/// # Errors
+///
+/// Returns [`PiecedError::LengthMismatch`] (and leaves `out` untouched)
+/// unless `out` holds exactly `a.len() + b.len()` chunks.
+///
+/// # Examples
+///
+/// ```
+/// let a = [[1, b'a'], [3, b'a'], [5, b'a']];
+/// let b = [[2, b'b'], [3, b'b']];
+/// let mut out = [[0; 2]; 5];
+/// pieced::merge_sorted_chunks(&a, &b, &mut out, |x, y| x[0].cmp(&y[0])).unwrap();
+/// assert_eq!(out, [[1, b'a'], [2, b'b'], [3, b'a'], [3, b'b'], [5, b'a']]);
+/// ```
+pub fn merge_sorted_chunks<T: Clone, const N: usize>(
+    a: &[[T; N]],
+    b: &[[T; N]],
+    out: &mut [[T; N]],
+    mut cmp: impl FnMut(&[T; N], &[T; N]) -> Ordering,
+) -> Result<(), PiecedError> {
+    if a.len().checked_add(b.len()) != Some(out.len()) {
+        return Err(PiecedError::LengthMismatch {
+            expected: a.len().saturating_add(b.len()),
+            actual: out.len(),
+        });
+    }
+    let (mut a, mut b) = (a, b);
+    for slot in out {
+        let next = match (a.first(), b.first()) {
+            (Some(x), Some(y)) if cmp(y, x) == Ordering::Less => &mut b,
+            (Some(_), _) => &mut a,
+            (None, _) => &mut b,
+        };
+        let (chunk, rest) = next
+            .split_first()
+            .expect("out has room for exactly a and b");
+        slot.clone_from(chunk);
+        *next = rest;
+    }
+    Ok(())
+}
+
+/// Reorders the chunks so that the chunk at `index` is the one that would be there if they were sorted by `cmp`,
+/// with no greater chunk before it and no lesser chunk after it.
+///
+/// Returns the chunks before `index`, the chunk at `index`, and the chunks after it.
+/// This is [`select_nth_unstable_by`](slice::select_nth_unstable_by) at chunk granularity:
+/// whole chunks are compared and moved, in `O(chunks.len())` time on average.
+///
+/// # Panics
+///
+/// Panics if `index >= chunks.len()`.
+///
+/// # Examples
+///
+/// ```
+/// // (score, id) records; find the top 2 by score.
+/// let mut records = [5u8, 0, 9, 1, 2, 2, 7, 3, 1, 4];
+/// let chunks = pieced::as_exact_mut::<_, 2>(&mut records);
+/// let (top, _, _) = pieced::select_nth_chunk_unstable_by(chunks, 2, |a, b| b[0].cmp(&a[0]));
+/// top.sort();
+/// assert_eq!(top, [[7, 3], [9, 1]]);
+/// ```
+pub fn select_nth_chunk_unstable_by<T, const N: usize>(
+    chunks: &mut [[T; N]],
+    index: usize,
+    cmp: impl FnMut(&[T; N], &[T; N]) -> Ordering,
+) -> (&mut [[T; N]], &mut [T; N], &mut [[T; N]]) {
+    chunks.select_nth_unstable_by(index, cmp)
+}
+
+/// Returns the chunk with the greatest key, or [`None`] if there are no chunks.
+///
+/// Of several chunks with the greatest key, the last one is returned.
+///
+/// # Examples
+///
+/// ```
+/// let records = [[1, 5], [2, 9], [3, 9], [4, 0]];
+/// assert_eq!(pieced::max_chunk_by_key(&records, |[_, score]| *score), Some(&[3, 9]));
+/// ```
+pub fn max_chunk_by_key<T, const N: usize, K: Ord>(
+    chunks: &[[T; N]],
+    mut key: impl FnMut(&[T; N]) -> K,
+) -> Option<&[T; N]> {
+    chunks.iter().max_by_key(|chunk| key(chunk))
+}
+
+/// Returns the chunk with the least key, or [`None`] if there are no chunks.
+///
+/// Of several chunks with the least key, the first one is returned.
+///
+/// # Examples
+///
+/// ```
+/// let records = [[1, 5], [2, 0], [3, 0], [4, 9]];
+/// assert_eq!(pieced::min_chunk_by_key(&records, |[_, score]| *score), Some(&[2, 0]));
+/// ```
+pub fn min_chunk_by_key<T, const N: usize, K: Ord>(
+    chunks: &[[T; N]],
+    mut key: impl FnMut(&[T; N]) -> K,
+) -> Option<&[T; N]> {
+    chunks.iter().min_by_key(|chunk| key(chunk))
+}
+
+/// Sorts fixed-size byte records lexicographically, with a least-significant-digit radix sort.
+///
+/// This sorts in the same order as `chunks.sort()`, takes `O(N * chunks.len())` time,
+/// and allocates one scratch copy of `chunks`.
+/// Byte positions where every record agrees are skipped.
+///
+/// # Examples
+///
+/// ```
+/// let mut keys = [*b"dab", *b"abc", *b"dab", *b"aab", *b"cab"];
+/// pieced::sort_chunks_radix(&mut keys);
+/// assert_eq!(keys, [*b"aab", *b"abc", *b"cab", *b"dab", *b"dab"]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn sort_chunks_radix<const N: usize>(chunks: &mut [[u8; N]]) {
+    if chunks.len() < 2 {
+        return;
+    }
+    let mut scratch = vec![[0; N]; chunks.len()];
+    let mut sorted_into_scratch = false;
+    for byte in (0..N).rev() {
+        let (src, dst) = if sorted_into_scratch {
+            (&mut scratch[..], &mut *chunks)
+        } else {
+            (&mut *chunks, &mut scratch[..])
+        };
+        let mut offsets = [0; 256];
+        for chunk in &*src {
+            offsets[usize::from(chunk[byte])] += 1;
+        }
+        if offsets[usize::from(src[0][byte])] == src.len() {
+            continue;
+        }
+        let mut start = 0;
+        for offset in &mut offsets {
+            (*offset, start) = (start, start + *offset);
+        }
+        for chunk in &*src {
+            let offset = &mut offsets[usize::from(chunk[byte])];
+            dst[*offset] = *chunk;
+            *offset += 1;
+        }
+        sorted_into_scratch = !sorted_into_scratch;
+    }
+    if sorted_into_scratch {
+        chunks.copy_from_slice(&scratch);
+    }
+}
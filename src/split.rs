@@ -0,0 +1,65 @@
+/// Checks that `at` is sorted and in bounds of a slice of `len` elements.
+fn valid_splits(at: &[usize], len: usize) -> bool {
+    at.windows(2).all(|pair| pair[0] <= pair[1]) && at.last().is_none_or(|&last| last <= len)
+}
+
+/// Splits the slice at every index in `at`, returning the `M` parts before each index and the rest.
+///
+/// Part `i` is `slice[at[i - 1]..at[i]]` (starting from 0 for the first part),
+/// and the rest is `slice[at[M - 1]..]`.
+/// Returns [`None`] unless the indices are sorted (repeats are fine, and give empty parts) and in bounds.
+///
+/// # Examples
+///
+/// ```
+/// let buffer = b"HEADpayload!CRC";
+/// let ([header, payload], crc) = pieced::split_many(buffer, [4, 12]).unwrap();
+/// assert_eq!((header, payload, crc), (&b"HEAD"[..], &b"payload!"[..], &b"CRC"[..]));
+///
+/// assert!(pieced::split_many(buffer, [12, 4]).is_none());
+/// assert!(pieced::split_many(buffer, [4, 16]).is_none());
+/// ```
+pub fn split_many<T, const M: usize>(slice: &[T], at: [usize; M]) -> Option<([&[T]; M], &[T])> {
+    if !valid_splits(&at, slice.len()) {
+        return None;
+    }
+    let mut rest = slice;
+    let mut start = 0;
+    let parts = core::array::from_fn(|i| {
+        let (part, tail) = rest.split_at(at[i] - start);
+        (rest, start) = (tail, at[i]);
+        part
+    });
+    Some((parts, rest))
+}
+
+/// Mutable [`split_many`]: splits the slice into disjoint mutable parts at every index in `at`.
+///
+/// Returns [`None`] unless the indices are sorted and in bounds.
+///
+/// # Examples
+///
+/// ```
+/// let mut buffer = [0u8; 10];
+/// let ([header, payload], crc) = pieced::split_many_mut(&mut buffer, [2, 8]).unwrap();
+/// header.copy_from_slice(&[0xAB, 6]);
+/// payload.fill(1);
+/// crc.copy_from_slice(&[0xCC, 0xDD]);
+/// assert_eq!(buffer, [0xAB, 6, 1, 1, 1, 1, 1, 1, 0xCC, 0xDD]);
+/// ```
+pub fn split_many_mut<T, const M: usize>(
+    slice: &mut [T],
+    at: [usize; M],
+) -> Option<([&mut [T]; M], &mut [T])> {
+    if !valid_splits(&at, slice.len()) {
+        return None;
+    }
+    let mut rest = slice;
+    let mut start = 0;
+    let parts = core::array::from_fn(|i| {
+        let (part, tail) = core::mem::take(&mut rest).split_at_mut(at[i] - start);
+        (rest, start) = (tail, at[i]);
+        part
+    });
+    Some((parts, rest))
+}
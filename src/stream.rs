@@ -0,0 +1,189 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+use core::mem::MaybeUninit;
+
+/// Regroups a stream of arbitrarily sized slices into `N`-element chunks,
+/// carrying partial chunks over from one [`push`](Rechunker::push) to the next.
+///
+/// # Examples
+///
+/// ```
+/// let mut rechunker = pieced::Rechunker::<u8, 4>::new();
+/// let mut frames = vec![];
+/// for burst in [&b"ab"[..], b"cdefg", b"h", b"ij"] {
+///     rechunker.push(burst, |frame| frames.push(*frame));
+/// }
+/// assert_eq!(frames, [*b"abcd", *b"efgh"]);
+/// assert_eq!(rechunker.pending(), b"ij");
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct Rechunker<T, const N: usize> {
+    carry: Vec<T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> Rechunker<T, N> {
+    /// Creates a rechunker with nothing pending.
+    pub const fn new() -> Self {
+        Self { carry: Vec::new() }
+    }
+
+    /// Returns the elements of the incomplete chunk that's still waiting for input.
+    pub fn pending(&self) -> &[T] {
+        &self.carry
+    }
+
+    /// Drops the pending elements.
+    pub fn clear(&mut self) {
+        self.carry.clear();
+    }
+
+    /// Consumes the rechunker, returning the pending elements.
+    pub fn finish(self) -> Vec<T> {
+        self.carry
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Clone, const N: usize> Rechunker<T, N> {
+    /// Feeds `input` to the rechunker, calling `emit` with every chunk it completes, in order.
+    ///
+    /// Chunks that lie entirely within `input` are passed to `emit` straight from `input`;
+    /// only the elements of chunks straddling two pushes are copied.
+    ///
+    /// # Compile-time errors
+    ///
+    /// Fails to compile if `N` is 0.
+    pub fn push(&mut self, mut input: &[T], mut emit: impl FnMut(&[T; N])) {
+        crate::assert_nonzero::<N>();
+        if !self.carry.is_empty() {
+            // Taken out while `emit` runs, so a panic in it can't leave the chunk to be sent again.
+            let mut carry = core::mem::take(&mut self.carry);
+            let (fill, tail) = input.split_at((N - carry.len()).min(input.len()));
+            carry.extend_from_slice(fill);
+            input = tail;
+            if let Some(chunk) = carry.first_chunk() {
+                emit(chunk);
+                carry.clear();
+            }
+            self.carry = carry;
+            if !self.carry.is_empty() {
+                return;
+            }
+        }
+        let (chunks, rest) = crate::as_with_rest::<T, N>(input);
+        chunks.iter().for_each(&mut emit);
+        self.carry.extend_from_slice(rest);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> Default for Rechunker<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// [`Rechunker`], without the heap: the incomplete chunk is carried in an inline `N`-element buffer.
+///
+/// # Examples
+///
+/// ```
+/// let mut rechunker = pieced::ArrayRechunker::<u8, 3>::new();
+/// let mut sum = 0;
+/// for burst in [&[1, 2][..], &[3, 4, 5, 6, 7]] {
+///     rechunker.push(burst, |frame| sum += frame.iter().product::<u8>());
+/// }
+/// assert_eq!(sum, 1 * 2 * 3 + 4 * 5 * 6);
+/// assert_eq!(rechunker.pending(), &[7]);
+/// ```
+pub struct ArrayRechunker<T, const N: usize> {
+    buf: [MaybeUninit<T>; N],
+    /// `buf[..len]` is initialized.
+    len: usize,
+}
+
+impl<T, const N: usize> ArrayRechunker<T, N> {
+    /// Creates a rechunker with nothing pending.
+    pub const fn new() -> Self {
+        Self {
+            buf: [const { MaybeUninit::uninit() }; N],
+            len: 0,
+        }
+    }
+
+    /// Returns the elements of the incomplete chunk that's still waiting for input.
+    pub const fn pending(&self) -> &[T] {
+        let (pending, _) = self.buf.split_at(self.len);
+        // SAFETY: `buf[..len]` is initialized.
+        unsafe { crate::raw::assume_init(pending) }
+    }
+
+    /// Drops the pending elements.
+    pub fn clear(&mut self) {
+        let len = core::mem::take(&mut self.len);
+        // SAFETY: `buf[..len]` was initialized, and is no longer considered so.
+        unsafe { core::ptr::drop_in_place(crate::raw::assume_init_mut(&mut self.buf[..len])) };
+    }
+}
+
+impl<T: Clone, const N: usize> ArrayRechunker<T, N> {
+    /// Feeds `input` to the rechunker, calling `emit` with every chunk it completes, in order.
+    ///
+    /// Chunks that lie entirely within `input` are passed to `emit` straight from `input`;
+    /// only the elements of chunks straddling two pushes are copied.
+    ///
+    /// # Compile-time errors
+    ///
+    /// Fails to compile if `N` is 0.
+    pub fn push(&mut self, mut input: &[T], mut emit: impl FnMut(&[T; N])) {
+        crate::assert_nonzero::<N>();
+        if self.len != 0 {
+            let (fill, tail) = input.split_at((N - self.len).min(input.len()));
+            self.extend(fill);
+            input = tail;
+            if self.len < N {
+                return;
+            }
+            // SAFETY: all `N` elements of `buf` are initialized,
+            // and are no longer considered so once moved out.
+            let chunk = unsafe { crate::raw::read_chunk(&self.buf) };
+            self.len = 0;
+            // Owned here, the chunk is dropped exactly once even if `emit` panics.
+            emit(&chunk);
+        }
+        let (chunks, rest) = crate::as_with_rest::<T, N>(input);
+        chunks.iter().for_each(&mut emit);
+        self.extend(rest);
+    }
+
+    /// Appends `elements`, which must fit in the buffer, to the pending ones.
+    fn extend(&mut self, elements: &[T]) {
+        for (slot, element) in self.buf[self.len..].iter_mut().zip(elements) {
+            slot.write(element.clone());
+            self.len += 1;
+        }
+    }
+}
+
+impl<T, const N: usize> Default for ArrayRechunker<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Drop for ArrayRechunker<T, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for ArrayRechunker<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArrayRechunker")
+            .field("pending", &self.pending())
+            .finish()
+    }
+}
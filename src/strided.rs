@@ -0,0 +1,114 @@
+use core::iter::StepBy;
+use core::slice;
+
+/// A view of every `step`-th chunk of a slice of chunks, starting at some offset.
+///
+/// Created by [`Strided::new`]; [`every_nth_chunk`] iterates over one directly.
+///
+/// # Examples
+///
+/// ```
+/// use pieced::Strided;
+/// let frames = [[0u8; 4], [1; 4], [2; 4], [3; 4], [4; 4], [5; 4], [6; 4]];
+/// let keyframes = Strided::new(&frames, 3, 1);
+/// assert_eq!(keyframes.len(), 2);
+/// assert_eq!(keyframes.get(1), Some(&[4; 4]));
+/// assert_eq!(keyframes.get(2), None);
+/// assert!(keyframes.iter().eq([&[1; 4], &[4; 4]]));
+/// ```
+#[derive(Debug)]
+pub struct Strided<'a, T, const N: usize> {
+    chunks: &'a [[T; N]],
+    step: usize,
+}
+
+impl<'a, T, const N: usize> Strided<'a, T, N> {
+    /// Views every `step`-th chunk, starting with the chunk at `offset`.
+    ///
+    /// An `offset` past the end gives an empty view.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is 0.
+    pub const fn new(chunks: &'a [[T; N]], step: usize, offset: usize) -> Self {
+        assert!(step != 0, "pieced::Strided::new requires a non-zero step");
+        let chunks = match chunks.split_at_checked(offset) {
+            Some((_, chunks)) => chunks,
+            None => &[],
+        };
+        Self { chunks, step }
+    }
+
+    /// Returns the number of chunks in the view.
+    pub const fn len(&self) -> usize {
+        self.chunks.len().div_ceil(self.step)
+    }
+
+    /// Returns `true` if the view has no chunks.
+    pub const fn is_empty(&self) -> bool {
+        self.chunks.is_empty()
+    }
+
+    /// Returns the `index`-th chunk of the view, or [`None`] if it's out of bounds.
+    pub const fn get(&self, index: usize) -> Option<&'a [T; N]> {
+        match index.checked_mul(self.step) {
+            Some(at) if at < self.chunks.len() => Some(&self.chunks[at]),
+            _ => None,
+        }
+    }
+
+    /// Iterates over the chunks of the view.
+    pub fn iter(&self) -> StepBy<slice::Iter<'a, [T; N]>> {
+        self.chunks.iter().step_by(self.step)
+    }
+}
+
+impl<T, const N: usize> Clone for Strided<'_, T, N> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const N: usize> Copy for Strided<'_, T, N> {}
+
+impl<'a, T, const N: usize> IntoIterator for Strided<'a, T, N> {
+    type Item = &'a [T; N];
+    type IntoIter = StepBy<slice::Iter<'a, [T; N]>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &Strided<'a, T, N> {
+    type Item = &'a [T; N];
+    type IntoIter = StepBy<slice::Iter<'a, [T; N]>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterates over every `step`-th chunk, starting with the chunk at `offset`.
+///
+/// An `offset` past the end yields nothing.
+///
+/// # Panics
+///
+/// Panics if `step` is 0.
+///
+/// # Examples
+///
+/// ```
+/// let frames = [*b"f0", *b"f1", *b"f2", *b"f3", *b"f4", *b"f5"];
+/// let sampled: Vec<_> = pieced::every_nth_chunk(&frames, 4, 0).collect();
+/// assert_eq!(sampled, [b"f0", b"f4"]);
+/// assert_eq!(pieced::every_nth_chunk(&frames, 2, 9).count(), 0);
+/// ```
+pub fn every_nth_chunk<T, const N: usize>(
+    chunks: &[[T; N]],
+    step: usize,
+    offset: usize,
+) -> StepBy<slice::Iter<'_, [T; N]>> {
+    Strided::new(chunks, step, offset).iter()
+}
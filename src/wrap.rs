@@ -0,0 +1,77 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Marks `Self` as a transparent wrapper around `[T; N]`,
+/// so that chunks can be viewed as `Self` with [`as_wrapped`] and friends.
+///
+/// # Safety
+///
+/// `Self` must be a `#[repr(transparent)]` struct whose only non-zero-sized field is a `[T; N]`,
+/// with no invariants beyond those of `[T; N]`:
+/// every `[T; N]` must be a valid `Self`.
+///
+/// # Examples
+///
+/// ```
+/// #[derive(Debug, PartialEq)]
+/// #[repr(transparent)]
+/// struct Rgb([u8; 3]);
+/// // SAFETY: `Rgb` is a `repr(transparent)` wrapper around `[u8; 3]`.
+/// unsafe impl pieced::FromChunk<u8, 3> for Rgb {}
+///
+/// let (pixels, rest): (&[Rgb], _) = pieced::as_wrapped(&[255, 0, 0, 0, 255, 0, 9]);
+/// assert_eq!(pixels, [Rgb([255, 0, 0]), Rgb([0, 255, 0])]);
+/// assert_eq!(rest, &[9]);
+/// ```
+pub unsafe trait FromChunk<T, const N: usize>: Sized {}
+
+/// Views the chunks of the slice as wrappers, like [`as_with_rest`](crate::as_with_rest).
+///
+/// # Compile-time errors
+///
+/// Fails to compile if `N` is 0.
+pub const fn as_wrapped<T, const N: usize, W: FromChunk<T, N>>(slice: &[T]) -> (&[W], &[T]) {
+    let (chunks, rest) = crate::as_with_rest(slice);
+    (wrap_chunks(chunks), rest)
+}
+
+/// Views the chunks of the slice as mutable wrappers, like [`as_with_rest`](crate::as_with_rest).
+///
+/// # Compile-time errors
+///
+/// Fails to compile if `N` is 0.
+pub fn as_wrapped_mut<T, const N: usize, W: FromChunk<T, N>>(
+    slice: &mut [T],
+) -> (&mut [W], &mut [T]) {
+    let (chunks, rest) = crate::as_with_rest_mut(slice);
+    (wrap_chunks_mut(chunks), rest)
+}
+
+/// Views a slice of chunks as a slice of wrappers.
+pub const fn wrap_chunks<T, const N: usize, W: FromChunk<T, N>>(chunks: &[[T; N]]) -> &[W] {
+    // SAFETY: `W` is a transparent wrapper around `[T; N]` (see `FromChunk`).
+    unsafe { crate::raw::cast_slice(chunks) }
+}
+
+/// Views a mutable slice of chunks as a mutable slice of wrappers.
+pub fn wrap_chunks_mut<T, const N: usize, W: FromChunk<T, N>>(chunks: &mut [[T; N]]) -> &mut [W] {
+    // SAFETY: `W` is a transparent wrapper around `[T; N]` (see `FromChunk`).
+    unsafe { crate::raw::cast_slice_mut(chunks) }
+}
+
+/// Converts a vector of chunks into a vector of wrappers, without reallocating.
+///
+/// # Examples
+///
+/// ```
+/// use pieced::ChunkKey;
+/// let keys: Vec<ChunkKey<2>> = pieced::wrap_vec(vec![*b"hi", *b"yo"]);
+/// assert_eq!(keys, [ChunkKey(*b"hi"), ChunkKey(*b"yo")]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn wrap_vec<T, const N: usize, W: FromChunk<T, N>>(chunks: Vec<[T; N]>) -> Vec<W> {
+    let (ptr, len, cap, alloc) = crate::owned::parts::vec_into_parts(chunks);
+    // SAFETY: `W` is a transparent wrapper around `[T; N]` (see `FromChunk`),
+    // so the allocation has the same layout either way.
+    unsafe { crate::owned::parts::vec_from_parts(ptr.cast(), len, cap, alloc) }
+}